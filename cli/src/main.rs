@@ -1,11 +1,29 @@
 use anyhow::{Context, Result};
-use chrono::Utc;
-use clap::{Args, Parser, Subcommand};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use colored::*;
 use fixture_scraper::{
-    arsenal::ArsenalScraper, validation::ValidatedFixture, FixtureScraper,
+    arsenal::ArsenalScraper,
+    changes::{ChangeKind, ChangeLog, DiffEngine},
+    clock::{Clock, FixedClock, SystemClock},
+    csv::{CsvColumn, CsvExporter},
+    dedupe::Deduplicator,
+    ics::IcsExporter,
+    planner::{WatchPartyPlanner, WatchPriorities},
+    policy::ValidationPolicy,
+    reminders::ReminderPolicy,
+    rules::RuleSet,
+    schema::{FixtureFile, JSON_SCHEMA, SCRAPER_VERSION},
+    status::{self, FixtureStatus},
+    store::{self, FixtureQuery},
+    templates::{EventTemplate, PRESETS},
+    validation::ValidatedFixture,
+    Exporter, Fixture, FixtureScraper, Squad,
 };
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Parser)]
 #[command(name = "calpal")]
@@ -27,11 +45,27 @@ enum Commands {
 
     #[command(about = "Show information about supported teams")]
     Teams,
+
+    #[command(about = "Find watch-party conflicts across followed teams")]
+    Plan(PlanArgs),
+
+    #[command(about = "Compare two saved scrapes (exit 0: unchanged, 1: changed, 2: error)")]
+    Diff(DiffArgs),
+
+    #[command(about = "Export fixtures for calendars and other tools")]
+    Export(ExportArgs),
+
+    #[command(about = "Print the JSON Schema for files written by 'calpal scrape --output'")]
+    Schema,
 }
 
 #[derive(Args)]
 struct ScrapeArgs {
-    #[arg(short, long, help = "Team to scrape (arsenal, springboks, all)")]
+    #[arg(
+        short,
+        long,
+        help = "Team to scrape (arsenal, arsenal-women, arsenal-academy, all)"
+    )]
     team: String,
 
     #[arg(short, long, help = "Output file for JSON data")]
@@ -39,6 +73,196 @@ struct ScrapeArgs {
 
     #[arg(long, help = "Pretty print JSON output")]
     pretty: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Json,
+        requires = "output",
+        help = "Format of the --output file"
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        value_name = "NAMES",
+        value_delimiter = ',',
+        help = "CSV/TSV columns from: date, time, utc, team, opponent, home_away, venue, competition, sport, status, validation, issues, confidence, url"
+    )]
+    columns: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "SCORE",
+        value_parser = parse_confidence,
+        help = "Hide fixtures with parse confidence below this level (0.0-1.0)"
+    )]
+    min_confidence: Option<f32>,
+
+    #[arg(
+        long,
+        value_name = "DATETIME",
+        help = "Validate as if it were this time (RFC 3339, e.g. 2025-08-08T12:00:00Z)"
+    )]
+    as_of: Option<DateTime<Utc>>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Validation policy JSON (date range, time windows, severities, suppressions)"
+    )]
+    policy: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Last saved scrape, to report changes and mark moved or dropped fixtures"
+    )]
+    previous: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Output file for the JSON change log against --previous or --store"
+    )]
+    changes: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "previous",
+        help = "Fixture store to compare with and update (.db/.sqlite file, or a directory of JSON)"
+    )]
+    store: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct PlanArgs {
+    #[arg(
+        short,
+        long = "input",
+        value_name = "FILE",
+        required = true,
+        help = "Fixtures saved with 'calpal scrape --output' (repeat for each team)"
+    )]
+    inputs: Vec<PathBuf>,
+
+    #[arg(
+        long,
+        default_value = "Europe/London",
+        help = "Viewer's timezone for listing conflicts"
+    )]
+    timezone: String,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Watch priorities JSON (teams and competitions, most important first)"
+    )]
+    priorities: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "MINUTES",
+        default_value_t = 60,
+        help = "Events closer together than this count as back-to-back"
+    )]
+    buffer_minutes: i64,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    #[arg(help = "Earlier scrape saved with 'calpal scrape --output'")]
+    old: PathBuf,
+
+    #[arg(help = "Later scrape saved with 'calpal scrape --output'")]
+    new: PathBuf,
+
+    #[arg(long, help = "Print the change log as JSON instead of a table")]
+    json: bool,
+}
+
+#[derive(Args)]
+struct ExportArgs {
+    #[arg(long, value_enum, default_value_t = ExportFormat::Ics, help = "Output format")]
+    format: ExportFormat,
+
+    #[arg(
+        short,
+        long = "input",
+        value_name = "FILE",
+        required_unless_present = "store",
+        help = "Fixtures saved with 'calpal scrape --output' (repeatable)"
+    )]
+    inputs: Vec<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "inputs",
+        help = "Export the fixture store instead; event SEQUENCE follows each fixture's history"
+    )]
+    store: Option<PathBuf>,
+
+    #[arg(short, long, help = "Output file (defaults to stdout)")]
+    output: Option<PathBuf>,
+
+    #[arg(
+        long,
+        default_value = "Europe/London",
+        help = "Timezone for event times"
+    )]
+    timezone: String,
+
+    #[arg(
+        long,
+        default_value = "CalPal Fixtures",
+        help = "Calendar name shown by clients"
+    )]
+    calendar_name: String,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Reminder policy JSON (reminders per team, competition and importance)"
+    )]
+    reminders: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PRESET|FILE",
+        default_value = "verbose",
+        help = "Event text: 'verbose', 'terse' (ARS v CHE (PL)), 'plain' (ASCII) or a template JSON file"
+    )]
+    template: String,
+
+    #[arg(
+        long,
+        value_name = "NAMES",
+        value_delimiter = ',',
+        help = "CSV/TSV columns from: date, time, utc, team, opponent, home_away, venue, competition, sport, status, validation, issues, confidence, url"
+    )]
+    columns: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum ExportFormat {
+    /// iCalendar (RFC 5545), for Google Calendar, Outlook and Apple Calendar
+    Ics,
+    /// Comma-separated values, for spreadsheets
+    Csv,
+    /// Tab-separated values, for spreadsheets
+    Tsv,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum OutputFormat {
+    /// Versioned fixture file, readable by 'calpal plan', 'diff' and 'export'
+    Json,
+    /// Comma-separated values, for spreadsheets
+    Csv,
+    /// Tab-separated values, for spreadsheets
+    Tsv,
 }
 
 #[tokio::main]
@@ -48,6 +272,23 @@ async fn main() -> Result<()> {
     match cli.command {
         Commands::Scrape(args) => scrape_command(args, cli.verbose).await,
         Commands::Teams => teams_command(),
+        Commands::Plan(args) => plan_command(args, cli.verbose),
+        Commands::Export(args) => export_command(args),
+        Commands::Schema => {
+            print!("{JSON_SCHEMA}");
+            Ok(())
+        }
+        Commands::Diff(args) => {
+            // Exit codes follow diff(1) so cron jobs can branch on them
+            let code = match diff_command(args, cli.verbose) {
+                Ok(changed) => i32::from(changed),
+                Err(e) => {
+                    eprintln!("{}", format!("❌ {e:#}").bright_red());
+                    2
+                }
+            };
+            std::process::exit(code);
+        }
     }
 }
 
@@ -60,14 +301,60 @@ async fn scrape_command(args: ScrapeArgs, verbose: bool) -> Result<()> {
 
     let team_name = args.team.to_lowercase();
 
+    // Historical cut-off and date range follow --as-of when given
+    let clock: Arc<dyn Clock> = match args.as_of {
+        Some(as_of) => {
+            println!(
+                "{}",
+                format!(
+                    "🕰️  Validating as of {}",
+                    as_of.format("%Y-%m-%d %H:%M UTC")
+                )
+                .bright_yellow()
+            );
+            Arc::new(FixedClock::new(as_of))
+        }
+        None => Arc::new(SystemClock),
+    };
+    let arsenal = || ArsenalScraper::new().with_clock(clock.clone());
+
+    // A policy replaces the standard validation rules
+    let rules = match &args.policy {
+        Some(path) => {
+            let policy = ValidationPolicy::load(path)?;
+            if verbose {
+                println!(
+                    "{}",
+                    format!("📋 Validation policy: {}", path.display()).dimmed()
+                );
+            }
+            policy.rule_set()?
+        }
+        None => RuleSet::standard(),
+    };
+    let validation = Validation {
+        rules: &rules,
+        clock: clock.as_ref(),
+    };
+
     match team_name.as_str() {
-        "arsenal" => scrape_team_fixtures(ArsenalScraper::new(), &args, verbose).await,
+        "arsenal" => scrape_team_fixtures(arsenal(), &args, &validation, verbose).await,
+        "arsenal-women" => {
+            let scraper = arsenal().with_squads(&[Squad::Women]);
+            scrape_team_fixtures(scraper, &args, &validation, verbose).await
+        }
+        "arsenal-academy" => {
+            let scraper = arsenal().with_squads(&[Squad::Academy]);
+            scrape_team_fixtures(scraper, &args, &validation, verbose).await
+        }
         "all" => {
             println!(
                 "{}",
                 "🌟 Scraping all supported teams...".bright_blue().bold()
             );
-            scrape_team_fixtures(ArsenalScraper::new(), &args, verbose).await?;
+            // One scraper covers every followed squad so --output gets a single file
+            let scraper = arsenal().with_squads(&[Squad::Men, Squad::Women]);
+            scrape_team_fixtures(scraper, &args, &validation, verbose).await?;
             println!(
                 "{}",
                 "✅ All teams scraped successfully!".bright_green().bold()
@@ -79,15 +366,25 @@ async fn scrape_command(args: ScrapeArgs, verbose: bool) -> Result<()> {
                 "{}",
                 format!("❌ Unsupported team: {team_name}").bright_red()
             );
-            eprintln!("{}", "💡 Supported teams: arsenal, all".bright_yellow());
+            eprintln!(
+                "{}",
+                "💡 Supported teams: arsenal, arsenal-women, arsenal-academy, all".bright_yellow()
+            );
             std::process::exit(1);
         }
     }
 }
 
+/// How scraped fixtures are validated once duplicates are merged.
+struct Validation<'a> {
+    rules: &'a RuleSet,
+    clock: &'a dyn Clock,
+}
+
 async fn scrape_team_fixtures<T: FixtureScraper>(
     scraper: T,
     args: &ScrapeArgs,
+    validation: &Validation<'_>,
     verbose: bool,
 ) -> Result<()> {
     let team_name = scraper.team_name();
@@ -130,13 +427,106 @@ async fn scrape_team_fixtures<T: FixtureScraper>(
         }
     };
 
+    // One event per real match, validated under the user's policy
+    let scraped = fixtures.len();
+    let fixtures = Deduplicator::new().dedupe(fixtures.into_iter().map(|v| v.fixture).collect());
+    if fixtures.len() < scraped {
+        println!(
+            "{}",
+            format!("🔗 Merged {} duplicate fixtures", scraped - fixtures.len()).bright_blue()
+        );
+    }
+
+    let mut fixture_store = match &args.store {
+        Some(path) => Some(store::open(path)?),
+        None => None,
+    };
+    let previous: Option<Vec<Fixture>> = match (&args.previous, &fixture_store) {
+        (Some(path), _) => Some(
+            load_fixtures(path)?
+                .into_iter()
+                .map(|v| v.fixture)
+                .collect(),
+        ),
+        (None, Some(fixture_store)) => {
+            // Played fixtures drop off the club page, so only compare those still to finish
+            let now = validation.clock.now();
+            let stored = fixture_store.query(&FixtureQuery::new())?;
+            Some(
+                stored
+                    .into_iter()
+                    .map(|s| s.fixture)
+                    .filter(|f| f.expected_end() >= now)
+                    .collect(),
+            )
+        }
+        (None, None) => None,
+    };
+    // Only the teams in this scrape, so other teams' earlier fixtures don't read as removed
+    let teams: HashSet<String> = fixtures.iter().map(|f| f.team_label()).collect();
+    let previous = previous.map(|previous| {
+        previous
+            .into_iter()
+            .filter(|f| teams.contains(&f.team_label()))
+            .collect::<Vec<_>>()
+    });
+
+    // Compare with the last scrape so moved or dropped fixtures aren't silently lost
+    let fixtures = match previous {
+        Some(previous) => {
+            let change_log = DiffEngine::new().diff(&previous, &fixtures, validation.clock.now());
+            print_change_log(&change_log);
+            if let Some(changes_path) = &args.changes {
+                let json = serde_json::to_string_pretty(&change_log)
+                    .context("Failed to serialize change log")?;
+                std::fs::write(changes_path, json).context("Failed to write change log")?;
+                println!(
+                    "{}",
+                    format!("💾 Saved change log to {}", changes_path.display()).bright_green()
+                );
+            }
+
+            status::reconcile(&previous, fixtures, validation.clock.now())
+        }
+        None => fixtures,
+    };
+
+    if let Some(fixture_store) = &mut fixture_store {
+        let summary = fixture_store.upsert(&fixtures, validation.clock.now())?;
+        println!(
+            "{}",
+            format!(
+                "🗄️  Stored: {} new, {} updated, {} unchanged",
+                summary.inserted, summary.updated, summary.unchanged
+            )
+            .bright_blue()
+        );
+    }
+    let fixtures = validation.rules.validate_all(fixtures, validation.clock);
+
+    // Drop fixtures the parser wasn't sure enough about
+    let fixtures = filter_by_confidence(fixtures, args.min_confidence);
+
     // Display fixtures in a beautiful table format
     print_fixtures_table(&fixtures, verbose);
 
     // Save to file if requested
     if let Some(output_path) = &args.output {
-        save_fixtures_to_file(&fixtures, output_path, args.pretty)
-            .context("Failed to save fixtures to file")?;
+        match args.format {
+            OutputFormat::Json => {
+                save_fixtures_to_file(&fixtures, team_name, output_path, args.pretty)
+                    .context("Failed to save fixtures to file")?
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                let exporter = csv_exporter(
+                    args.format == OutputFormat::Tsv,
+                    &args.columns,
+                    chrono_tz::Europe::London,
+                )?;
+                std::fs::write(output_path, exporter.export(&fixtures, Utc::now()))
+                    .context("Failed to save fixtures to file")?;
+            }
+        }
 
         println!(
             "{}",
@@ -152,6 +542,47 @@ async fn scrape_team_fixtures<T: FixtureScraper>(
     Ok(())
 }
 
+/// `--min-confidence` is a score, not a percentage; NaN would hide nothing.
+fn parse_confidence(value: &str) -> Result<f32, String> {
+    let score: f32 = value
+        .parse()
+        .map_err(|_| format!("'{value}' is not a number"))?;
+    if (0.0..=1.0).contains(&score) {
+        Ok(score)
+    } else {
+        Err(format!("{value} is outside 0.0-1.0"))
+    }
+}
+
+fn filter_by_confidence(
+    fixtures: Vec<ValidatedFixture>,
+    min_confidence: Option<f32>,
+) -> Vec<ValidatedFixture> {
+    let Some(min_confidence) = min_confidence else {
+        return fixtures;
+    };
+
+    let total = fixtures.len();
+    let kept: Vec<ValidatedFixture> = fixtures
+        .into_iter()
+        .filter(|fixture| fixture.confidence.score >= min_confidence)
+        .collect();
+
+    if kept.len() < total {
+        println!(
+            "{}",
+            format!(
+                "🙈 Hid {} fixtures below {:.0}% confidence",
+                total - kept.len(),
+                min_confidence * 100.0
+            )
+            .bright_yellow()
+        );
+    }
+
+    kept
+}
+
 fn print_fixtures_table(fixtures: &[ValidatedFixture], verbose: bool) {
     if fixtures.is_empty() {
         println!("{}", "⚠️  No fixtures found".bright_yellow());
@@ -183,7 +614,7 @@ fn print_fixtures_table(fixtures: &[ValidatedFixture], verbose: bool) {
         let london_time = fixture.to_london_time();
         let date_str = london_time.format("%a %b %d").to_string();
         let time_str = london_time.format("%H:%M").to_string();
-        let match_str = format!("{} vs {}", fixture.team, fixture.opponent);
+        let match_str = fixture.title();
 
         // Color coding for teams
         let colored_match = if fixture.team.contains("Arsenal") {
@@ -202,39 +633,385 @@ fn print_fixtures_table(fixtures: &[ValidatedFixture], verbose: bool) {
             fixture.competition.bright_blue()
         );
 
+        match fixture.status {
+            FixtureStatus::Scheduled => {}
+            FixtureStatus::Rescheduled { previous } => println!(
+                "     {}",
+                format!(
+                    "🔁 RESCHEDULED (was {})",
+                    previous
+                        .with_timezone(&chrono_tz::Europe::London)
+                        .format("%a %b %d %H:%M")
+                )
+                .bright_yellow()
+            ),
+            FixtureStatus::Completed => {
+                println!("     {}", "✔️  COMPLETED".dimmed())
+            }
+            status => println!(
+                "     {}",
+                format!("🚫 {}", status.to_string().to_uppercase()).bright_red()
+            ),
+        }
+
         if verbose {
+            let (_, latest_end) = fixture.time_window();
             println!(
                 "     {}",
                 format!(
-                    "UTC: {} | Parsing: {}",
+                    "UTC: {} | Ends: {} (latest {}) | Parsing: {} | Confidence: {}",
                     fixture.datetime.format("%Y-%m-%d %H:%M UTC"),
-                    fixture.parse_metadata.timezone_assumptions
+                    fixture.expected_end().format("%Y-%m-%d %H:%M UTC"),
+                    latest_end.format("%H:%M UTC"),
+                    fixture.parse_metadata.timezone_assumptions,
+                    validated_fixture.confidence
                 )
                 .dimmed()
             );
+
+            if fixture.sources.len() > 1 {
+                let sources: Vec<String> = fixture.sources.iter().map(|s| s.to_string()).collect();
+                println!(
+                    "     {}",
+                    format!("Merged from: {}", sources.join("; ")).dimmed()
+                );
+            }
         }
     }
 
     println!();
 }
 
+fn print_change_log(change_log: &ChangeLog) {
+    if change_log.is_empty() {
+        println!("{}", "🟰 No changes since the last scrape".dimmed());
+        return;
+    }
+
+    println!(
+        "\n{}",
+        format!("📝 {} Changes Since Last Scrape", change_log.len())
+            .bright_magenta()
+            .bold()
+            .underline()
+    );
+    println!();
+
+    for change in &change_log.changes {
+        let line = format!("  {}", change.describe());
+        match change.kind {
+            ChangeKind::Added => println!("{}", line.bright_green()),
+            ChangeKind::Removed => println!("{}", line.bright_red()),
+            _ => println!("{}", line.bright_yellow()),
+        }
+    }
+}
+
 fn save_fixtures_to_file(
     fixtures: &[ValidatedFixture],
+    scraper_name: &str,
     path: &PathBuf,
     pretty: bool,
 ) -> Result<()> {
-    let json_data = if pretty {
-        serde_json::to_string_pretty(fixtures)
-    } else {
-        serde_json::to_string(fixtures)
-    }
-    .context("Failed to serialize fixtures to JSON")?;
+    let json_data = FixtureFile::new(fixtures.to_vec(), Utc::now())
+        .with_scraper(scraper_name, SCRAPER_VERSION)
+        .to_json(pretty)?;
 
     std::fs::write(path, json_data).context("Failed to write JSON data to file")?;
 
     Ok(())
 }
 
+/// Fixtures saved with 'calpal scrape --output', from any schema version.
+fn load_fixtures(path: &Path) -> Result<Vec<ValidatedFixture>> {
+    Ok(load_fixture_file(path)?.fixtures)
+}
+
+fn load_fixture_file(path: &Path) -> Result<FixtureFile> {
+    FixtureFile::load(path)
+        .with_context(|| format!("{} is not a saved fixture file", path.display()))
+}
+
+fn plan_command(args: PlanArgs, verbose: bool) -> Result<()> {
+    print_banner();
+
+    let timezone: Tz = args
+        .timezone
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Unknown timezone: {}", args.timezone))?;
+
+    let priorities = match &args.priorities {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            WatchPriorities::from_json(&json)?
+        }
+        None => WatchPriorities::default(),
+    };
+
+    let mut fixtures: Vec<ValidatedFixture> = Vec::new();
+    for path in &args.inputs {
+        let loaded = load_fixtures(path)?;
+        if verbose {
+            println!(
+                "{}",
+                format!("📂 {} fixtures from {}", loaded.len(), path.display()).dimmed()
+            );
+        }
+        fixtures.extend(loaded);
+    }
+
+    let planner = WatchPartyPlanner::new(timezone)
+        .with_buffer(chrono::Duration::minutes(args.buffer_minutes))
+        .with_priorities(priorities);
+    let conflicts = planner.find_conflicts(&fixtures);
+
+    if conflicts.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "✅ No watch-party conflicts across {} fixtures",
+                fixtures.len()
+            )
+            .bright_green()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\n{}",
+        format!("⚔️  {} Watch-Party Conflicts ({timezone})", conflicts.len())
+            .bright_magenta()
+            .bold()
+            .underline()
+    );
+    println!();
+
+    for conflict in &conflicts {
+        println!("  {}", conflict.describe(timezone));
+        println!(
+            "     {}",
+            format!("🎉 Host: {}", conflict.hosted().title()).bright_green()
+        );
+        println!(
+            "     {}",
+            format!("⏭️  Skip: {}", conflict.skipped().title()).dimmed()
+        );
+    }
+
+    println!();
+    Ok(())
+}
+
+/// Returns whether anything changed between the two scrapes.
+fn diff_command(args: DiffArgs, verbose: bool) -> Result<bool> {
+    let fixtures = |file: FixtureFile| -> Vec<Fixture> {
+        file.fixtures.into_iter().map(|v| v.fixture).collect()
+    };
+    let (old, new) = (load_fixture_file(&args.old)?, load_fixture_file(&args.new)?);
+    // Fixtures played before the new scrape aren't reschedules or removals
+    let scraped_at = new.generated_at;
+    let (old, new) = (fixtures(old), fixtures(new));
+    let change_log = DiffEngine::new().diff(&old, &new, scraped_at);
+
+    if args.json {
+        let json =
+            serde_json::to_string_pretty(&change_log).context("Failed to serialize change log")?;
+        println!("{json}");
+        return Ok(!change_log.is_empty());
+    }
+
+    print_banner();
+    if verbose {
+        println!(
+            "{}",
+            format!(
+                "📂 {} fixtures in {}, {} in {}",
+                old.len(),
+                args.old.display(),
+                new.len(),
+                args.new.display()
+            )
+            .dimmed()
+        );
+    }
+
+    if change_log.is_empty() {
+        println!("{}", "✅ No changes between the two scrapes".bright_green());
+        return Ok(false);
+    }
+
+    print_change_table(&change_log);
+    Ok(true)
+}
+
+fn print_change_table(change_log: &ChangeLog) {
+    println!(
+        "\n{}",
+        format!("📝 {} Changes", change_log.len())
+            .bright_magenta()
+            .bold()
+            .underline()
+    );
+    println!();
+
+    println!(
+        "{:<20} {:<25} {:<12} {:<25} {:<25}",
+        "Change".bright_white().bold(),
+        "Match".bright_white().bold(),
+        "Date".bright_cyan().bold(),
+        "Was".bright_red().bold(),
+        "Now".bright_green().bold()
+    );
+    println!("{}", "─".repeat(110).dimmed());
+
+    let london = |datetime: &DateTime<Utc>| {
+        datetime
+            .with_timezone(&chrono_tz::Europe::London)
+            .format("%a %b %d %H:%M")
+            .to_string()
+    };
+
+    for change in &change_log.changes {
+        let fixture = &change.fixture;
+        let (label, was, now) = match &change.kind {
+            ChangeKind::Added => ("Added".bright_green(), String::new(), String::new()),
+            ChangeKind::Removed => ("Removed".bright_red(), String::new(), String::new()),
+            ChangeKind::KickOffMoved { from, to } => {
+                ("Kick-off moved".bright_yellow(), london(from), london(to))
+            }
+            ChangeKind::VenueChanged { from, to } => {
+                ("Venue changed".bright_yellow(), from.clone(), to.clone())
+            }
+            ChangeKind::OpponentConfirmed { from, to } => {
+                ("Opponent confirmed".bright_blue(), from.clone(), to.clone())
+            }
+            ChangeKind::CompetitionChanged { from, to } => (
+                "Competition changed".bright_yellow(),
+                from.clone(),
+                to.clone(),
+            ),
+        };
+
+        println!(
+            "{:<20} {:<25} {:<12} {:<25} {:<25}",
+            label,
+            fixture.title(),
+            fixture
+                .to_london_time()
+                .format("%a %b %d")
+                .to_string()
+                .bright_cyan(),
+            was.dimmed(),
+            now
+        );
+    }
+
+    println!();
+}
+
+fn export_command(args: ExportArgs) -> Result<()> {
+    let timezone: Tz = args
+        .timezone
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Unknown timezone: {}", args.timezone))?;
+    let mut exporter = IcsExporter::new()
+        .with_timezone(timezone)
+        .with_calendar_name(&args.calendar_name)
+        .with_template(load_template(&args.template)?);
+    if let Some(path) = &args.reminders {
+        exporter = exporter.with_reminders(ReminderPolicy::load(path)?);
+    }
+
+    let fixtures = match &args.store {
+        Some(path) => {
+            let fixture_store = store::open(path)?;
+            let mut fixtures = Vec::new();
+            let mut sequences = Vec::new();
+            for stored in fixture_store.query(&FixtureQuery::new())? {
+                // Each stored change is a new revision for calendar clients
+                let revisions = fixture_store.history(&stored.id)?.len();
+                sequences.push(u32::try_from(revisions.saturating_sub(1)).unwrap_or(u32::MAX));
+                fixtures.push(stored.fixture);
+            }
+            let validated = RuleSet::standard().validate_all(fixtures, &SystemClock);
+            for (uid, sequence) in IcsExporter::uids(&validated).iter().zip(sequences) {
+                exporter = exporter.with_sequence(uid, sequence);
+            }
+            validated
+        }
+        None => {
+            let mut fixtures = Vec::new();
+            for path in &args.inputs {
+                fixtures.extend(load_fixtures(path)?);
+            }
+            fixtures
+        }
+    };
+
+    let (exported, count) = match args.format {
+        ExportFormat::Ics => (
+            exporter.export(&fixtures, Utc::now()),
+            fixtures.iter().filter(|f| f.is_usable()).count(),
+        ),
+        ExportFormat::Csv | ExportFormat::Tsv => {
+            let exporter = csv_exporter(args.format == ExportFormat::Tsv, &args.columns, timezone)?;
+            (exporter.export(&fixtures, Utc::now()), fixtures.len())
+        }
+    };
+
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, exported)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            eprintln!(
+                "{}",
+                format!("📆 Exported {count} fixtures to {}", path.display()).bright_green()
+            );
+        }
+        None => print!("{exported}"),
+    }
+
+    Ok(())
+}
+
+/// Grounds the supported clubs play home fixtures at, for the Home/Away column.
+const HOME_GROUNDS: [(&str, &str); 2] =
+    [("Arsenal", "Emirates Stadium"), ("Arsenal", "Meadow Park")];
+
+/// CSV or TSV exporter with the named columns (the defaults when none are given).
+fn csv_exporter(tsv: bool, columns: &[String], timezone: Tz) -> Result<CsvExporter> {
+    let mut exporter = if tsv {
+        CsvExporter::tsv()
+    } else {
+        CsvExporter::new()
+    }
+    .with_timezone(timezone);
+    for (team, venue) in HOME_GROUNDS {
+        exporter = exporter.with_home_ground(team, venue);
+    }
+    if !columns.is_empty() {
+        let columns = columns
+            .iter()
+            .map(|name| CsvColumn::from_name(name))
+            .collect::<Result<Vec<_>, _>>()?;
+        exporter = exporter.with_columns(columns);
+    }
+    Ok(exporter)
+}
+
+/// A preset by name, otherwise a template JSON file.
+fn load_template(spec: &str) -> Result<EventTemplate> {
+    match EventTemplate::preset(spec) {
+        Some(template) => Ok(template),
+        None if Path::new(spec).exists() => Ok(EventTemplate::load(Path::new(spec))?),
+        None => anyhow::bail!(
+            "Unknown template '{spec}': use one of {} or a template JSON file",
+            PRESETS.join(", ")
+        ),
+    }
+}
+
 fn teams_command() -> Result<()> {
     print_banner();
 
@@ -259,6 +1036,13 @@ fn teams_command() -> Result<()> {
         "https://www.arsenal.com/fixtures".bright_blue()
     );
 
+    println!(
+        "{:<15} {:<30} {:<40}",
+        "Arsenal Women".bright_red(),
+        "Football (Women's Super League)".bright_green(),
+        "https://www.arsenal.com/fixtures/women".bright_blue()
+    );
+
     println!(
         "{:<15} {:<30} {:<40}",
         "Springboks".bright_green(),
@@ -275,6 +1059,22 @@ fn teams_command() -> Result<()> {
         "{}",
         "💡 Use 'calpal scrape --team all' to scrape all teams".bright_yellow()
     );
+    println!(
+        "{}",
+        "💡 Use 'calpal plan -i <file> -i <file>' to find watch-party conflicts".bright_yellow()
+    );
+    println!(
+        "{}",
+        "💡 Use 'calpal diff <old> <new>' to compare two saved scrapes".bright_yellow()
+    );
+    println!(
+        "{}",
+        "💡 Use 'calpal export --format ics -i <file>' to build a calendar".bright_yellow()
+    );
+    println!(
+        "{}",
+        "💡 Use 'calpal export --format csv -i <file>' for a spreadsheet".bright_yellow()
+    );
 
     Ok(())
 }
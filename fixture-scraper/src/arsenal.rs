@@ -1,15 +1,55 @@
 use crate::browser::BrowserScraper;
-use crate::{validation::ValidatedFixture, Fixture, FixtureScraper, ScrapeError};
+use crate::clock::{Clock, SystemClock};
+use crate::confidence::FixtureField;
+use crate::duration::DurationDefaults;
+use crate::parsing::{
+    DateTimeParser, KickOffReading, KickOffSource, MatchedFormat, ParseMetadata, ParsingStrategy,
+};
+use crate::status::FixtureStatus;
+use crate::{
+    validation::ValidatedFixture, Fixture, FixtureScraper, FixtureSource, ScrapeError, Squad,
+};
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use chrono_tz::Europe::London;
 use reqwest::Client;
 use scraper::{Html, Selector};
+use std::sync::Arc;
+
+/// A single fixture list published by the club for one of its squads.
+///
+/// Arsenal's men's, women's and academy fixtures live on separate pages that
+/// share the same accordion markup, so one scraper can walk all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SquadPage {
+    pub squad: Squad,
+    pub url: String,
+}
+
+impl SquadPage {
+    /// The club's own fixture page for a squad.
+    pub fn for_squad(squad: Squad) -> Self {
+        let url = match squad {
+            Squad::Men => "https://www.arsenal.com/fixtures",
+            Squad::Women => "https://www.arsenal.com/fixtures/women",
+            Squad::Academy => "https://www.arsenal.com/fixtures/academy",
+        };
+
+        Self {
+            squad,
+            url: url.to_string(),
+        }
+    }
+}
 
 pub struct ArsenalScraper {
     client: Client,
     browser: Option<BrowserScraper>,
-    base_url: String,
+    pages: Vec<SquadPage>,
+    team_name: String,
     use_browser: bool,
+    clock: Arc<dyn Clock>,
+    durations: DurationDefaults,
 }
 
 impl ArsenalScraper {
@@ -17,8 +57,11 @@ impl ArsenalScraper {
         Self {
             client: Client::new(),
             browser: None,
-            base_url: "https://www.arsenal.com/fixtures".to_string(),
+            pages: vec![SquadPage::for_squad(Squad::Men)],
+            team_name: "Arsenal".to_string(),
             use_browser: true, // Default to browser for Arsenal's dynamic content
+            clock: Arc::new(SystemClock),
+            durations: DurationDefaults::standard(),
         }
     }
 
@@ -29,8 +72,11 @@ impl ArsenalScraper {
         Ok(Self {
             client: Client::new(),
             browser: Some(browser),
-            base_url: "https://www.arsenal.com/fixtures".to_string(),
+            pages: vec![SquadPage::for_squad(Squad::Men)],
+            team_name: "Arsenal".to_string(),
             use_browser: true,
+            clock: Arc::new(SystemClock),
+            durations: DurationDefaults::standard(),
         })
     }
 
@@ -39,41 +85,92 @@ impl ArsenalScraper {
         Self {
             client: Client::new(),
             browser: None,
-            base_url: "https://www.arsenal.com/fixtures".to_string(),
+            pages: vec![SquadPage::for_squad(Squad::Men)],
+            team_name: "Arsenal".to_string(),
             use_browser: false,
+            clock: Arc::new(SystemClock),
+            durations: DurationDefaults::standard(),
         }
     }
 
-}
+    /// Scrape the given squads instead of just the men's first team.
+    ///
+    /// A single squad scraper reports itself under the squad's team name
+    /// (e.g. "Arsenal Women") so it can be subscribed to as its own team.
+    pub fn with_squads(self, squads: &[Squad]) -> Self {
+        let pages = squads
+            .iter()
+            .map(|&squad| SquadPage::for_squad(squad))
+            .collect();
+        self.with_pages(pages)
+    }
 
-#[async_trait]
-impl FixtureScraper for ArsenalScraper {
-    async fn scrape(&self) -> Result<Vec<ValidatedFixture>, ScrapeError> {
+    /// Scrape custom squad pages (e.g. a mirror or a cup-specific listing).
+    pub fn with_pages(mut self, pages: Vec<SquadPage>) -> Self {
+        self.team_name = match pages.as_slice() {
+            [page] => match page.squad.team_suffix() {
+                Some(suffix) => format!("Arsenal {suffix}"),
+                None => "Arsenal".to_string(),
+            },
+            _ => "Arsenal".to_string(),
+        };
+        self.pages = pages;
+        self
+    }
+
+    /// Validate scraped fixtures as of `clock` instead of the system time.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Derive each fixture's expected duration from `durations`.
+    pub fn with_durations(mut self, durations: DurationDefaults) -> Self {
+        self.durations = durations;
+        self
+    }
+
+    /// Squads this scraper covers, in scraping order.
+    pub fn squads(&self) -> Vec<Squad> {
+        self.pages.iter().map(|page| page.squad).collect()
+    }
+
+    async fn fetch_html(&self, url: &str) -> Result<String, ScrapeError> {
         // Get HTML content using browser if available, otherwise fall back to HTTP
-        let html_content = if self.use_browser && self.browser.is_some() {
+        match &self.browser {
             // Use headless browser for dynamic content
-            let browser = self.browser.as_ref().unwrap();
-            browser.get_rendered_html(&self.base_url).await?
-        } else {
-            // Fall back to traditional HTTP scraping
-            let response = self
-                .client
-                .get(&self.base_url)
-                .header("User-Agent", "CalPal/1.0 (Sports Calendar Scraper)")
-                .send()
-                .await
-                .map_err(|e| {
-                    ScrapeError::Network(format!("Failed to fetch Arsenal fixtures: {e}"))
-                })?;
-
-            response
-                .text()
-                .await
-                .map_err(|e| ScrapeError::Network(format!("Failed to read response body: {e}")))?
-        };
+            Some(browser) if self.use_browser => browser.get_rendered_html(url).await,
+            _ => {
+                // Fall back to traditional HTTP scraping
+                let response = self
+                    .client
+                    .get(url)
+                    .header("User-Agent", "CalPal/1.0 (Sports Calendar Scraper)")
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        ScrapeError::Network(format!("Failed to fetch Arsenal fixtures: {e}"))
+                    })?;
+
+                response
+                    .text()
+                    .await
+                    .map_err(|e| ScrapeError::Network(format!("Failed to read response body: {e}")))
+            }
+        }
+    }
 
+    /// Extract fixtures for one squad from a rendered fixture page.
+    ///
+    /// Each squad page is filtered on its own: fixtures are tagged with the
+    /// page's squad, so a women's page never leaks into the men's list.
+    pub fn parse_fixtures(
+        &self,
+        html_content: &str,
+        squad: Squad,
+    ) -> Result<Vec<ValidatedFixture>, ScrapeError> {
         // Parse HTML
-        let document = Html::parse_document(&html_content);
+        let document = Html::parse_document(html_content);
 
         // Define selectors for accordion fixtures (the real fixture data)
         let fixture_selector = Selector::parse("div.accordions article")
@@ -91,6 +188,12 @@ impl FixtureScraper for ArsenalScraper {
         let opponent_selector = Selector::parse("h3.visually-hidden")
             .map_err(|e| ScrapeError::Parse(format!("Invalid opponent selector: {e}")))?;
 
+        let status_selector = Selector::parse(".event-info__status")
+            .map_err(|e| ScrapeError::Parse(format!("Invalid status selector: {e}")))?;
+
+        // Visible and hidden kick-off texts have no year or offset: London time, scraper's clock
+        let text_parser = DateTimeParser::new(London).with_clock(self.clock.clone());
+
         let mut fixtures = Vec::new();
 
         // Extract fixtures from HTML (now targeting accordion fixtures)
@@ -101,16 +204,26 @@ impl FixtureScraper for ArsenalScraper {
                 .and_then(|el| el.value().attr("datetime"))
                 .unwrap_or("");
 
-            let display_time = datetime_element
-                .and_then(|el| el.text().next())
-                .unwrap_or("Unknown Time");
+            let display_time = datetime_element.and_then(|el| el.text().next());
+
+            // Visually-hidden header, e.g. "Villarreal - Wed Aug 6 - 18:00"
+            let header_text = fixture_element
+                .select(&opponent_selector)
+                .next()
+                .and_then(|el| el.text().next());
+
+            // Placeholders below lower the fixture's parse confidence
+            let mut field_fallbacks = Vec::new();
 
             // Extract venue information
             let venue_text = fixture_element
                 .select(&venue_selector)
                 .next()
                 .and_then(|el| el.text().next())
-                .unwrap_or("TBD Venue")
+                .unwrap_or_else(|| {
+                    field_fallbacks.push(FixtureField::Venue);
+                    "TBD Venue"
+                })
                 .to_string();
 
             // Extract competition
@@ -118,73 +231,228 @@ impl FixtureScraper for ArsenalScraper {
                 .select(&competition_selector)
                 .next()
                 .and_then(|el| el.text().next())
-                .unwrap_or("Unknown Competition")
+                .unwrap_or_else(|| {
+                    field_fallbacks.push(FixtureField::Competition);
+                    "Unknown Competition"
+                })
                 .to_string();
 
-            // Extract opponent from the visually-hidden header (e.g., "Villarreal - Wed Aug 6 - 18:00")
-            let opponent = fixture_element
-                .select(&opponent_selector)
-                .next()
-                .and_then(|el| el.text().next())
+            // Extract opponent from the visually-hidden header
+            let opponent = header_text
                 .and_then(|text| text.split(" - ").next()) // Take first part before " - "
-                .unwrap_or("TBD Opponent")
+                .unwrap_or_else(|| {
+                    field_fallbacks.push(FixtureField::Opponent);
+                    "TBD Opponent"
+                })
                 .trim()
                 .to_string();
 
-            // Parse datetime from ISO format
-            if !datetime_str.is_empty() {
-                match chrono::DateTime::parse_from_rfc3339(datetime_str) {
-                    Ok(parsed_datetime) => {
-                        let utc_datetime = parsed_datetime.with_timezone(&Utc);
-
-                        // Create ParseMetadata for the datetime
-                        let metadata = crate::parsing::ParseMetadata {
-                            original_source: format!("{display_time} ({datetime_str})"),
-                            weekday_mismatch: None, // ISO format parsing is exact
-                            timezone_assumptions: "Parsed from ISO datetime attribute".to_string(),
-                            parsing_strategy: crate::parsing::ParsingStrategy::ExactMatch,
-                        };
-
-                        // Create fixture with proper venue and opponent
-                        let fixture = Fixture::new(
-                            "Arsenal".to_string(),
-                            opponent,
-                            utc_datetime,
-                            venue_text,
-                            competition_text,
-                            metadata,
-                        );
+            let Some((utc_datetime, mut metadata)) =
+                parse_kick_off(&text_parser, datetime_str, display_time, header_text)
+            else {
+                eprintln!("Warning: No parsable kick-off found for fixture against {opponent}");
+                continue;
+            };
+            metadata.field_fallbacks = field_fallbacks;
+
+            // Create fixture with proper venue and opponent
+            let fixture = Fixture::new(
+                "Arsenal".to_string(),
+                opponent,
+                utc_datetime,
+                venue_text,
+                competition_text,
+                metadata,
+            )
+            .with_squad(squad);
+
+            // "P-P" or "FT" in the status label; "Match Postponed" anywhere on the card
+            let card_text = fixture_element.text().collect::<Vec<_>>().join(" ");
+            let status = fixture_element
+                .select(&status_selector)
+                .next()
+                .and_then(|label| FixtureStatus::from_label(&label.text().collect::<String>()))
+                .or_else(|| FixtureStatus::from_markup(&card_text));
+            let fixture = match status {
+                Some(status) => fixture.with_status(status),
+                None => fixture,
+            };
+
+            // The club doesn't publish a duration, so every consumer gets ours
+            let duration = self.durations.for_fixture(&fixture);
+            let fixture = fixture.with_expected_duration(duration);
+
+            // Wrap in validation system
+            let validated_fixture = ValidatedFixture::with_clock(fixture, self.clock.as_ref());
+            fixtures.push(validated_fixture);
+        }
 
-                        // Wrap in validation system
-                        let validated_fixture = ValidatedFixture::new(fixture);
-                        fixtures.push(validated_fixture);
-                    }
-                    Err(e) => {
-                        eprintln!(
-                            "Warning: Failed to parse ISO datetime '{datetime_str}': {e}"
-                        );
-                    }
-                }
-            } else {
-                eprintln!("Warning: No datetime found for fixture");
+        Ok(fixtures)
+    }
+}
+
+/// Read every kick-off the article states and pick the most reliable one.
+///
+/// The ISO `datetime` attribute wins when it parses; otherwise the visible text
+/// and then the hidden header are parsed as London time so the fixture isn't
+/// lost. All readings are kept in the metadata for validation to cross-check.
+fn parse_kick_off(
+    text_parser: &DateTimeParser,
+    datetime_attribute: &str,
+    display_time: Option<&str>,
+    header_text: Option<&str>,
+) -> Option<(DateTime<Utc>, ParseMetadata)> {
+    let attribute = (!datetime_attribute.is_empty()).then(|| {
+        let parsed = DateTime::parse_from_rfc3339(datetime_attribute)
+            .map(|datetime| datetime.with_timezone(&Utc));
+        if let Err(e) = &parsed {
+            eprintln!("Warning: Failed to parse ISO datetime '{datetime_attribute}': {e}");
+        }
+        parsed.ok()
+    });
+
+    let parse_text =
+        |date: &str, time: &str| text_parser.parse_with_weekday_tolerance(date, time).ok();
+    let visible = display_time.map(|text| {
+        let (date, time) = split_date_time(text);
+        (text, parse_text(&date, &time))
+    });
+    let header = header_text.map(|text| {
+        // "Opponent - Sat Aug 15 - 15:00": the date and time are the last two parts
+        let parts: Vec<&str> = text.rsplitn(3, " - ").collect();
+        let parsed = match parts.as_slice() {
+            [time, date, _] => parse_text(date, time),
+            _ => None,
+        };
+        (text, parsed)
+    });
+
+    let mut readings = Vec::new();
+    if let Some(parsed) = attribute {
+        readings.push(KickOffReading {
+            source: KickOffSource::DatetimeAttribute,
+            text: datetime_attribute.to_string(),
+            parsed,
+        });
+    }
+    for (source, reading) in [
+        (KickOffSource::VisibleText, &visible),
+        (KickOffSource::HiddenHeader, &header),
+    ] {
+        if let Some((text, parsed)) = reading {
+            readings.push(KickOffReading {
+                source,
+                text: text.to_string(),
+                parsed: parsed.as_ref().map(|(datetime, _)| *datetime),
+            });
+        }
+    }
+
+    let (datetime, mut metadata) = match attribute.flatten() {
+        Some(datetime) => {
+            let metadata = ParseMetadata {
+                original_source: format!(
+                    "{} ({datetime_attribute})",
+                    display_time.unwrap_or("Unknown Time")
+                ),
+                weekday_mismatch: None, // ISO format parsing is exact
+                timezone_assumptions: "Parsed from ISO datetime attribute".to_string(),
+                parsing_strategy: ParsingStrategy::ExactMatch,
+                date_format: Some(MatchedFormat::rfc3339()),
+                ..Default::default()
+            };
+            (datetime, metadata)
+        }
+        None => visible
+            .and_then(|(_, parsed)| parsed)
+            .or_else(|| header.and_then(|(_, parsed)| parsed))?,
+    };
+
+    metadata.kick_off_readings = readings;
+    Some((datetime, metadata))
+}
+
+/// Merge the results of each squad page, tagging fixtures with their page.
+///
+/// One squad's page being down shouldn't empty the others, so failed pages
+/// are reported and skipped; the scrape only fails when every page does.
+fn combine_pages(
+    pages: Vec<(&SquadPage, Result<Vec<ValidatedFixture>, ScrapeError>)>,
+) -> Result<Vec<ValidatedFixture>, ScrapeError> {
+    let mut fixtures = Vec::new();
+    let mut first_error = None;
+    let page_count = pages.len();
+    let mut failed = 0;
+
+    for (page, result) in pages {
+        match result {
+            Ok(page_fixtures) => {
+                let source = FixtureSource::new("Arsenal", &page.url).with_section("accordion");
+                fixtures.extend(page_fixtures.into_iter().map(|mut validated| {
+                    validated.fixture.sources.push(source.clone());
+                    validated
+                }));
+            }
+            Err(e) => {
+                eprintln!(
+                    "Warning: Skipping {} fixtures from {}: {e}",
+                    page.squad, page.url
+                );
+                failed += 1;
+                first_error.get_or_insert(e);
             }
         }
+    }
 
-        if fixtures.is_empty() {
-            return Err(ScrapeError::MissingElement(
-                "No fixtures found on Arsenal page".to_string(),
-            ));
+    if failed == page_count {
+        if let Some(e) = first_error {
+            return Err(e);
         }
+    }
 
-        Ok(fixtures)
+    if fixtures.is_empty() {
+        return Err(ScrapeError::MissingElement(
+            "No fixtures found on Arsenal page".to_string(),
+        ));
+    }
+
+    Ok(fixtures)
+}
+
+/// Split "Sat 15 Aug 15:00" into its date and trailing time.
+fn split_date_time(text: &str) -> (String, String) {
+    let text = text.trim();
+    match text.rsplit_once(char::is_whitespace) {
+        Some((date, time)) if time.contains(':') => (date.trim().to_string(), time.to_string()),
+        _ => (text.to_string(), String::new()),
+    }
+}
+
+#[async_trait]
+impl FixtureScraper for ArsenalScraper {
+    async fn scrape(&self) -> Result<Vec<ValidatedFixture>, ScrapeError> {
+        let mut pages = Vec::with_capacity(self.pages.len());
+
+        for page in &self.pages {
+            let fixtures = match self.fetch_html(&page.url).await {
+                Ok(html_content) => self.parse_fixtures(&html_content, page.squad),
+                Err(e) => Err(e),
+            };
+            pages.push((page, fixtures));
+        }
+
+        combine_pages(pages)
     }
 
     fn team_name(&self) -> &str {
-        "Arsenal"
+        &self.team_name
     }
 
     fn source_url(&self) -> &str {
-        &self.base_url
+        self.pages
+            .first()
+            .map(|page| page.url.as_str())
+            .unwrap_or("https://www.arsenal.com/fixtures")
     }
 }
 
@@ -198,14 +466,272 @@ impl Default for ArsenalScraper {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::FixedClock;
+    use crate::duration::{CompetitionDuration, ExpectedDuration};
+    use crate::validation::{FixtureValidation, IssueCategory};
+    use chrono::{Duration, TimeZone};
+
+    const WOMEN_PAGE: &str = r#"
+        <div class="accordions">
+            <article>
+                <h3 class="visually-hidden">Chelsea Women - Sun Sep 7 - 12:30</h3>
+                <div class="event-info">
+                    <div class="event-info__date">
+                        <time datetime="2025-09-07T11:30:00.000Z">Sun 7 Sep 12:30</time>
+                    </div>
+                    <div class="event-info__venue">Emirates Stadium</div>
+                    <div class="event-info__extra">Women's Super League</div>
+                </div>
+            </article>
+        </div>
+        "#;
 
     #[test]
     fn test_arsenal_scraper_creation() {
         let scraper = ArsenalScraper::new();
         assert_eq!(scraper.team_name(), "Arsenal");
         assert_eq!(scraper.source_url(), "https://www.arsenal.com/fixtures");
+        assert_eq!(scraper.squads(), vec![Squad::Men]);
+    }
+
+    #[test]
+    fn test_arsenal_women_is_its_own_team() {
+        let scraper = ArsenalScraper::without_browser().with_squads(&[Squad::Women]);
+        assert_eq!(scraper.team_name(), "Arsenal Women");
+        assert_eq!(
+            scraper.source_url(),
+            "https://www.arsenal.com/fixtures/women"
+        );
+
+        let both = ArsenalScraper::without_browser().with_squads(&[Squad::Men, Squad::Women]);
+        assert_eq!(both.team_name(), "Arsenal");
+        assert_eq!(both.squads(), vec![Squad::Men, Squad::Women]);
+    }
+
+    #[test]
+    fn test_parse_fixtures_tags_squad() {
+        let html = WOMEN_PAGE;
+        let scraper = ArsenalScraper::without_browser().with_squads(&[Squad::Women]);
+        let fixtures = scraper
+            .parse_fixtures(html, Squad::Women)
+            .expect("Should parse squad page");
+
+        assert_eq!(fixtures.len(), 1);
+        let fixture = &fixtures[0].fixture;
+        assert_eq!(fixture.squad, Squad::Women);
+        assert_eq!(fixture.team_label(), "Arsenal Women");
+        assert_eq!(fixture.opponent, "Chelsea Women");
+        assert_eq!(fixture.competition, "Women's Super League");
+
+        // Validation follows the scraper's clock, not the real time
+        let before_kick_off = Utc.with_ymd_and_hms(2025, 9, 1, 12, 0, 0).unwrap();
+        let fixtures = scraper
+            .with_clock(Arc::new(FixedClock::new(before_kick_off)))
+            .parse_fixtures(html, Squad::Women)
+            .unwrap();
+        assert!(fixtures[0].is_usable());
+    }
+
+    #[test]
+    fn test_configured_durations_reach_the_fixture() {
+        let defaults = DurationDefaults::standard().with_competition(CompetitionDuration {
+            keyword: "Super League".to_string(),
+            sport: None,
+            minutes: Some(105),
+            extra_time_minutes: 0,
+        });
+        let fixtures = ArsenalScraper::without_browser()
+            .with_durations(defaults)
+            .parse_fixtures(WOMEN_PAGE, Squad::Women)
+            .unwrap();
+
+        let fixture = &fixtures[0].fixture;
+        assert_eq!(
+            fixture.expected_duration,
+            Some(ExpectedDuration::regular(105))
+        );
+        assert_eq!(
+            fixture.expected_end() - fixture.datetime,
+            Duration::minutes(105)
+        );
+    }
+
+    #[test]
+    fn test_one_failing_squad_page_keeps_the_others() {
+        let scraper = ArsenalScraper::without_browser();
+        let (men, women) = (
+            SquadPage::for_squad(Squad::Men),
+            SquadPage::for_squad(Squad::Women),
+        );
+        let outage = || Err(ScrapeError::Network("503 Service Unavailable".to_string()));
+
+        let fixtures = combine_pages(vec![
+            (&men, outage()),
+            (&women, scraper.parse_fixtures(WOMEN_PAGE, Squad::Women)),
+        ])
+        .expect("The women's page still scraped");
+        assert_eq!(fixtures.len(), 1);
+        assert_eq!(fixtures[0].fixture.sources[0].url, women.url);
+
+        // Only when every page fails does the scrape fail
+        let result = combine_pages(vec![(&men, outage()), (&women, outage())]);
+        assert!(matches!(result, Err(ScrapeError::Network(_))));
+    }
+
+    #[test]
+    fn test_kick_off_cross_check() {
+        let html = r#"
+        <div class="accordions">
+            <article>
+                <h3 class="visually-hidden">Newcastle United - Sat Aug 16 - 15:00</h3>
+                <div class="event-info">
+                    <div class="event-info__date">
+                        <time datetime="2025-08-16T14:00:00.000Z">Sat 16 Aug 17:30</time>
+                    </div>
+                    <div class="event-info__venue">St James' Park</div>
+                    <div class="event-info__extra">Premier League</div>
+                </div>
+            </article>
+        </div>
+        "#;
+
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap());
+        let fixtures = ArsenalScraper::without_browser()
+            .with_clock(Arc::new(clock))
+            .parse_fixtures(html, Squad::Men)
+            .unwrap();
+
+        let fixture = &fixtures[0];
+        assert_eq!(fixture.fixture.parse_metadata.kick_off_readings.len(), 3);
+        assert!(fixture
+            .fixture
+            .parse_metadata
+            .kick_off_readings
+            .iter()
+            .all(|reading| reading.parsed.is_some()));
+
+        let FixtureValidation::ValidWithWarnings(issues) = &fixture.validation else {
+            panic!("Expected the visible text disagreement to be flagged");
+        };
+        assert!(issues
+            .iter()
+            .any(|issue| issue.category == IssueCategory::SourceDisagreement
+                && issue.message.contains("visible text says Sat 16 Aug 17:30")));
     }
 
+    #[test]
+    fn test_text_fallback_without_datetime_attribute() {
+        let html = r#"
+        <div class="accordions">
+            <article>
+                <h3 class="visually-hidden">Newcastle United - Sat Aug 16 - 15:00</h3>
+                <div class="event-info">
+                    <div class="event-info__date">
+                        <time>Sat 16 Aug 15:00</time>
+                    </div>
+                    <div class="event-info__venue">St James' Park</div>
+                    <div class="event-info__extra">Premier League</div>
+                </div>
+            </article>
+        </div>
+        "#;
+
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap());
+        let fixtures = ArsenalScraper::without_browser()
+            .with_clock(Arc::new(clock))
+            .parse_fixtures(html, Squad::Men)
+            .unwrap();
+
+        assert_eq!(fixtures.len(), 1);
+        let fixture = &fixtures[0];
+        assert_eq!(
+            fixture.fixture.datetime,
+            Utc.with_ymd_and_hms(2025, 8, 16, 14, 0, 0).unwrap()
+        );
+        assert_eq!(
+            fixture.fixture.parse_metadata.original_source,
+            "Sat 16 Aug 15:00"
+        );
+        assert_eq!(fixture.validation, FixtureValidation::Valid);
+    }
+
+    #[test]
+    fn test_missing_venue_recorded_as_fallback() {
+        let html = r#"
+        <div class="accordions">
+            <article>
+                <h3 class="visually-hidden">Chelsea - Sat Aug 16 - 15:00</h3>
+                <div class="event-info">
+                    <div class="event-info__date">
+                        <time datetime="2025-08-16T14:00:00.000Z">Sat 16 Aug 15:00</time>
+                    </div>
+                    <div class="event-info__extra">Premier League</div>
+                </div>
+            </article>
+        </div>
+        "#;
+
+        let fixtures = ArsenalScraper::without_browser()
+            .parse_fixtures(html, Squad::Men)
+            .expect("Should parse fixture without venue");
+
+        let fixture = &fixtures[0];
+        assert_eq!(fixture.fixture.venue, "TBD Venue");
+        assert_eq!(
+            fixture.fixture.parse_metadata.field_fallbacks,
+            vec![FixtureField::Venue]
+        );
+        assert!(fixture.confidence.score < 1.0);
+    }
+
+    #[test]
+    fn test_postponed_marker_sets_status() {
+        let html = r#"
+        <div class="accordions">
+            <article>
+                <h3 class="visually-hidden">Chelsea - Sat Aug 16 - 15:00</h3>
+                <div class="event-info">
+                    <div class="event-info__date">
+                        <time datetime="2025-08-16T14:00:00.000Z">Sat 16 Aug 15:00</time>
+                    </div>
+                    <div class="event-info__venue">Emirates Stadium</div>
+                    <div class="event-info__extra">Premier League</div>
+                    <span class="event-info__status">P-P</span>
+                </div>
+            </article>
+        </div>
+        "#;
+
+        let fixtures = ArsenalScraper::without_browser()
+            .parse_fixtures(html, Squad::Men)
+            .expect("Should parse postponed fixture");
+
+        assert_eq!(fixtures[0].fixture.status, FixtureStatus::Postponed);
+    }
+
+    #[test]
+    fn test_status_codes_outside_the_label_are_ignored() {
+        let html = r#"
+        <div class="accordions">
+            <article>
+                <h3 class="visually-hidden">PP Rangers - Sat Aug 16 - 15:00</h3>
+                <div class="event-info">
+                    <div class="event-info__date">
+                        <time datetime="2025-08-16T14:00:00.000Z">Sat 16 Aug 15:00</time>
+                    </div>
+                    <div class="event-info__venue">FT Park</div>
+                    <div class="event-info__extra">Friendly</div>
+                </div>
+            </article>
+        </div>
+        "#;
+
+        let fixtures = ArsenalScraper::without_browser()
+            .parse_fixtures(html, Squad::Men)
+            .expect("Should parse fixture");
+
+        assert_eq!(fixtures[0].fixture.status, FixtureStatus::Scheduled);
+    }
 
     // Integration test - HTTP scraping (likely to fail with dynamic content)
     #[tokio::test]
@@ -0,0 +1,441 @@
+//! # Change Detection Between Scrapes
+//!
+//! Kick-offs move when broadcasters pick games, cup draws fill in opponents,
+//! and fixtures drop off a club's page. [`DiffEngine`] compares the current
+//! scrape with the last stored one and produces a [`ChangeLog`] that the CLI
+//! prints and that notifications and calendar updates can consume.
+//!
+//! ## Fixture identity
+//!
+//! A fixture has no stable ID on the source pages, so each current fixture is
+//! paired with a previous one of the same sport and team, preferring in turn:
+//!
+//! 1. Same opponent and competition
+//! 2. Same opponent, different competition
+//! 3. Same competition, with the previous opponent a placeholder
+//!
+//! Within each step the closest kick-off wins, and kick-offs further apart
+//! than [`DiffEngine::max_reschedule`] are never paired. A previous fixture
+//! that had finished by the time of the scrape has been played, so nothing
+//! new continues it. Unpaired fixtures are added, or removed if they were
+//! still to be played.
+
+use crate::confidence::{placeholder_fields, FixtureField};
+use crate::dedupe::normalise_team;
+use crate::status::FixtureStatus;
+use crate::Fixture;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What changed about a fixture.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ChangeKind {
+    /// New since the last scrape
+    Added,
+    /// No longer listed by the source
+    Removed,
+    KickOffMoved {
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    },
+    VenueChanged {
+        from: String,
+        to: String,
+    },
+    /// A placeholder opponent ("TBD Opponent") replaced by a real one
+    OpponentConfirmed {
+        from: String,
+        to: String,
+    },
+    CompetitionChanged {
+        from: String,
+        to: String,
+    },
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let london = |datetime: &DateTime<Utc>| {
+            datetime
+                .with_timezone(&chrono_tz::Europe::London)
+                .format("%a %d %b %H:%M")
+        };
+
+        match self {
+            ChangeKind::Added => write!(f, "Added"),
+            ChangeKind::Removed => write!(f, "Removed"),
+            ChangeKind::KickOffMoved { from, to } => {
+                write!(f, "Kick-off moved: {} → {}", london(from), london(to))
+            }
+            ChangeKind::VenueChanged { from, to } => write!(f, "Venue changed: {from} → {to}"),
+            ChangeKind::OpponentConfirmed { from, to } => {
+                write!(f, "Opponent confirmed: {from} → {to}")
+            }
+            ChangeKind::CompetitionChanged { from, to } => {
+                write!(f, "Competition changed: {from} → {to}")
+            }
+        }
+    }
+}
+
+/// One change to one fixture.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FixtureChange {
+    /// The fixture as it is now, or as it was last seen if removed
+    pub fixture: Fixture,
+    pub kind: ChangeKind,
+}
+
+impl FixtureChange {
+    /// One-line summary, e.g. "Arsenal vs Chelsea: Venue changed: Emirates → Wembley".
+    pub fn describe(&self) -> String {
+        format!("{}: {}", self.fixture.title(), self.kind)
+    }
+}
+
+/// Every change between two scrapes: current fixtures in order, then removals.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ChangeLog {
+    pub changes: Vec<FixtureChange>,
+}
+
+impl ChangeLog {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Changes to fixtures that were already listed, i.e. not added or removed.
+    pub fn updates(&self) -> impl Iterator<Item = &FixtureChange> {
+        self.changes
+            .iter()
+            .filter(|change| !matches!(change.kind, ChangeKind::Added | ChangeKind::Removed))
+    }
+}
+
+/// Compares two scrapes by fixture identity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffEngine {
+    /// How far a kick-off can move and still be the same fixture
+    pub max_reschedule: Duration,
+}
+
+impl Default for DiffEngine {
+    fn default() -> Self {
+        Self {
+            max_reschedule: Duration::days(60),
+        }
+    }
+}
+
+impl DiffEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_reschedule(mut self, max_reschedule: Duration) -> Self {
+        self.max_reschedule = max_reschedule;
+        self
+    }
+
+    /// For each current fixture, the index of the previous fixture it continues.
+    ///
+    /// A previous fixture that had finished by `now` only pairs with the same
+    /// fixture at the same kick-off, never with a later one.
+    pub fn pair(
+        &self,
+        previous: &[Fixture],
+        current: &[Fixture],
+        now: DateTime<Utc>,
+    ) -> Vec<Option<usize>> {
+        let mut pairs = vec![None; current.len()];
+        let mut taken = vec![false; previous.len()];
+
+        // Unchanged fixtures first, so results still listed on the page pair up
+        for (index, fixture) in current.iter().enumerate() {
+            let unchanged = previous.iter().enumerate().position(|(i, earlier)| {
+                !taken[i]
+                    && earlier.datetime == fixture.datetime
+                    && earlier.sport == fixture.sport
+                    && same_team(earlier, fixture)
+                    && same_opponent(earlier, fixture)
+                    && same_competition(earlier, fixture)
+            });
+            if let Some(i) = unchanged {
+                taken[i] = true;
+                pairs[index] = Some(i);
+            }
+        }
+
+        // Already played, so nothing else continues them
+        for (taken, earlier) in taken.iter_mut().zip(previous) {
+            *taken |= has_finished(earlier, now);
+        }
+
+        let identity_steps: [fn(&Fixture, &Fixture) -> bool; 3] = [
+            |earlier, now| same_opponent(earlier, now) && same_competition(earlier, now),
+            same_opponent,
+            |earlier, now| {
+                same_competition(earlier, now)
+                    && placeholder_fields(earlier).contains(&FixtureField::Opponent)
+            },
+        ];
+
+        for same_identity in identity_steps {
+            for (index, fixture) in current.iter().enumerate() {
+                if pairs[index].is_some() {
+                    continue;
+                }
+
+                let closest = previous
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, earlier)| {
+                        !taken[*i]
+                            && earlier.sport == fixture.sport
+                            && same_team(earlier, fixture)
+                            && (earlier.datetime - fixture.datetime).abs() <= self.max_reschedule
+                            && same_identity(earlier, fixture)
+                    })
+                    .min_by_key(|(_, earlier)| (earlier.datetime - fixture.datetime).abs())
+                    .map(|(i, _)| i);
+
+                if let Some(i) = closest {
+                    taken[i] = true;
+                    pairs[index] = Some(i);
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Everything that changed from `previous` to `current`, scraped at `now`.
+    pub fn diff(&self, previous: &[Fixture], current: &[Fixture], now: DateTime<Utc>) -> ChangeLog {
+        let pairs = self.pair(previous, current, now);
+        let mut changes = Vec::new();
+
+        for (fixture, pair) in current.iter().zip(&pairs) {
+            let Some(earlier) = pair.map(|i| &previous[i]) else {
+                changes.push(FixtureChange {
+                    fixture: fixture.clone(),
+                    kind: ChangeKind::Added,
+                });
+                continue;
+            };
+
+            for kind in field_changes(earlier, fixture) {
+                changes.push(FixtureChange {
+                    fixture: fixture.clone(),
+                    kind,
+                });
+            }
+        }
+
+        // A fixture already carried over as cancelled was reported when it went,
+        // and one that has been played dropping off the page isn't news
+        for (i, earlier) in previous.iter().enumerate() {
+            if !pairs.contains(&Some(i))
+                && earlier.status != FixtureStatus::Cancelled
+                && !has_finished(earlier, now)
+            {
+                changes.push(FixtureChange {
+                    fixture: earlier.clone(),
+                    kind: ChangeKind::Removed,
+                });
+            }
+        }
+
+        ChangeLog { changes }
+    }
+}
+
+fn has_finished(fixture: &Fixture, now: DateTime<Utc>) -> bool {
+    fixture.status == FixtureStatus::Completed || fixture.expected_end() < now
+}
+
+fn same_team(a: &Fixture, b: &Fixture) -> bool {
+    normalise_team(&a.team_label()) == normalise_team(&b.team_label())
+}
+
+fn same_opponent(a: &Fixture, b: &Fixture) -> bool {
+    normalise_team(&a.opponent) == normalise_team(&b.opponent)
+}
+
+fn same_competition(a: &Fixture, b: &Fixture) -> bool {
+    normalise_team(&a.competition) == normalise_team(&b.competition)
+}
+
+fn field_changes(earlier: &Fixture, now: &Fixture) -> Vec<ChangeKind> {
+    let mut changes = Vec::new();
+
+    if earlier.datetime != now.datetime {
+        changes.push(ChangeKind::KickOffMoved {
+            from: earlier.datetime,
+            to: now.datetime,
+        });
+    }
+    if !same_opponent(earlier, now) {
+        changes.push(ChangeKind::OpponentConfirmed {
+            from: earlier.opponent.clone(),
+            to: now.opponent.clone(),
+        });
+    }
+    if earlier.venue.trim() != now.venue.trim() {
+        changes.push(ChangeKind::VenueChanged {
+            from: earlier.venue.clone(),
+            to: now.venue.clone(),
+        });
+    }
+    if !same_competition(earlier, now) {
+        changes.push(ChangeKind::CompetitionChanged {
+            from: earlier.competition.clone(),
+            to: now.competition.clone(),
+        });
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, utc};
+
+    fn fixture(opponent: &str, competition: &str, day: u32, hour: u32) -> Fixture {
+        test_support::fixture()
+            .opponent(opponent)
+            .competition(competition)
+            .at(utc(2025, 10, day, hour, 0))
+            .build()
+    }
+
+    fn now() -> DateTime<Utc> {
+        utc(2025, 10, 1, 12, 0)
+    }
+
+    #[test]
+    fn test_unchanged_scrape_is_empty() {
+        let fixtures = vec![
+            fixture("Fulham", "Premier League", 4, 14),
+            fixture("Spurs", "Premier League", 18, 14),
+        ];
+        assert!(DiffEngine::new()
+            .diff(&fixtures, &fixtures, now())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_kick_off_moved_for_tv() {
+        let previous = vec![fixture("Spurs", "Premier League", 18, 14)];
+        let mut moved = fixture("Spurs", "Premier League", 19, 16);
+        moved.datetime += Duration::minutes(30);
+
+        let log = DiffEngine::new().diff(&previous, &[moved.clone()], now());
+        assert_eq!(log.len(), 1);
+        assert_eq!(
+            log.changes[0].kind,
+            ChangeKind::KickOffMoved {
+                from: previous[0].datetime,
+                to: moved.datetime
+            }
+        );
+        assert_eq!(
+            log.changes[0].describe(),
+            "Arsenal vs Spurs: Kick-off moved: Sat 18 Oct 15:00 → Sun 19 Oct 17:30"
+        );
+    }
+
+    #[test]
+    fn test_added_removed_and_field_changes() {
+        let previous = vec![
+            fixture("TBD Opponent", "Carabao Cup", 29, 19),
+            fixture("Burnley", "Premier League", 4, 14),
+            fixture("Chelsea", "Premier League", 11, 14),
+        ];
+
+        let mut relocated = fixture("Chelsea", "FA Cup", 11, 14);
+        relocated.venue = "Wembley Stadium".to_string();
+        let current = vec![
+            fixture("Brighton", "Carabao Cup", 29, 19),
+            relocated,
+            fixture("Fulham", "Premier League", 25, 14),
+        ];
+
+        let log = DiffEngine::new().diff(&previous, &current, now());
+        let kinds: Vec<String> = log.changes.iter().map(FixtureChange::describe).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "Arsenal vs Brighton: Opponent confirmed: TBD Opponent → Brighton",
+                "Arsenal vs Chelsea: Venue changed: Emirates Stadium → Wembley Stadium",
+                "Arsenal vs Chelsea: Competition changed: Premier League → FA Cup",
+                "Arsenal vs Fulham: Added",
+                "Arsenal vs Burnley: Removed",
+            ]
+        );
+        assert_eq!(log.updates().count(), 3);
+
+        // Reconciling keeps Burnley as cancelled; the next diff doesn't repeat it
+        let carried = crate::status::reconcile(&previous, current.clone(), now());
+        assert_eq!(carried[3].status, FixtureStatus::Cancelled);
+        assert!(DiffEngine::new().diff(&carried, &current, now()).is_empty());
+    }
+
+    #[test]
+    fn test_league_and_cup_against_same_opponent_pair_separately() {
+        let previous = vec![
+            fixture("Chelsea", "Premier League", 4, 14),
+            fixture("Chelsea", "FA Cup", 11, 14),
+        ];
+        // The cup tie moves closer to the league game; competition keeps them apart
+        let current = vec![
+            fixture("Chelsea", "FA Cup", 6, 19),
+            fixture("Chelsea", "Premier League", 4, 14),
+        ];
+
+        assert_eq!(
+            DiffEngine::new().pair(&previous, &current, now()),
+            vec![Some(1), Some(0)]
+        );
+    }
+
+    #[test]
+    fn test_played_fixture_is_not_continued() {
+        let league = [test_support::fixture()
+            .at(utc(2025, 11, 30, 16, 30))
+            .build()];
+        let cup = [test_support::fixture()
+            .competition("FA Cup")
+            .at(utc(2026, 1, 10, 15, 0))
+            .build()];
+        let after_league = utc(2025, 12, 5, 12, 0);
+
+        let engine = DiffEngine::new();
+        assert_eq!(engine.pair(&league, &cup, after_league), vec![None]);
+
+        // The cup tie is new; the league game was played, not removed
+        let log = engine.diff(&league, &cup, after_league);
+        assert_eq!(log.len(), 1);
+        assert_eq!(log.changes[0].kind, ChangeKind::Added);
+
+        // Before the league game it would have been a plausible reschedule
+        assert_eq!(engine.pair(&league, &cup, now()), vec![Some(0)]);
+    }
+
+    #[test]
+    fn test_played_fixture_still_listed_is_unchanged() {
+        let played = [test_support::fixture()
+            .at(utc(2025, 8, 16, 14, 0))
+            .build()
+            .with_status(FixtureStatus::Completed)];
+        let engine = DiffEngine::new();
+
+        assert_eq!(engine.pair(&played, &played, now()), vec![Some(0)]);
+        assert!(engine.diff(&played, &played, now()).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+//! # Injectable Clock
+//!
+//! Year inference, relative dates ("Tomorrow"), historical filtering and the
+//! accepted date range all depend on "now". Reading `Utc::now()` directly makes
+//! those results depend on when the code runs, so the parser, validator and
+//! scrapers ask a [`Clock`] instead:
+//!
+//! - [`SystemClock`] reads the real time (the default everywhere)
+//! - [`FixedClock`] always returns the same instant, for deterministic tests and
+//!   "what would the calendar look like next week" simulations
+
+use chrono::{DateTime, Duration, Utc};
+use std::fmt;
+use std::sync::Arc;
+
+/// Source of the current time.
+pub trait Clock: fmt::Debug + Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The real wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock frozen at one instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    now: DateTime<Utc>,
+}
+
+impl FixedClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self { now }
+    }
+
+    /// The same clock moved forward (or back, with a negative duration).
+    pub fn advanced_by(&self, duration: Duration) -> Self {
+        Self::new(self.now + duration)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+}
+
+/// Shared clocks, so one `Arc<dyn Clock>` can be handed to parser, validator and scrapers.
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fixed_clock_is_frozen() {
+        let at = Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap();
+        let clock = FixedClock::new(at);
+
+        assert_eq!(clock.now(), at);
+        assert_eq!(clock.now(), clock.now());
+        assert_eq!(
+            clock.advanced_by(Duration::days(7)).now(),
+            Utc.with_ymd_and_hms(2025, 8, 8, 12, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_shared_clock() {
+        let at = Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap();
+        let shared: Arc<dyn Clock> = Arc::new(FixedClock::new(at));
+
+        assert_eq!(shared.now(), at);
+        assert!(SystemClock.now() > at);
+    }
+}
@@ -0,0 +1,242 @@
+//! # Parse Confidence Scoring
+//!
+//! `ParseMetadata` records *what* the parser had to assume; this module turns
+//! those assumptions into a single number between 0 and 1 so that validation,
+//! the CLI and exporters can rank and filter fixtures.
+//!
+//! Every assumption multiplies the score by `1 - penalty`, so a fixture that
+//! needed a weekday-tolerant parse *and* has a placeholder venue scores lower
+//! than either on its own, but never drops below zero. The reasons are kept
+//! alongside the score so a low number can always be explained.
+
+use crate::parsing::ParsingStrategy;
+use crate::validation::IssueSeverity;
+use crate::Fixture;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A fixture field filled with a placeholder because the source didn't provide it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum FixtureField {
+    Opponent,
+    Venue,
+    Competition,
+}
+
+impl FixtureField {
+    /// How much a placeholder in this field lowers confidence.
+    fn penalty(&self) -> f32 {
+        match self {
+            FixtureField::Opponent => 0.15,
+            FixtureField::Venue => 0.1,
+            FixtureField::Competition => 0.05,
+        }
+    }
+}
+
+impl fmt::Display for FixtureField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixtureField::Opponent => write!(f, "opponent"),
+            FixtureField::Venue => write!(f, "venue"),
+            FixtureField::Competition => write!(f, "competition"),
+        }
+    }
+}
+
+/// One assumption that lowered a fixture's confidence.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfidenceFactor {
+    pub reason: String,
+    pub penalty: f32,
+}
+
+/// How much a fixture's date, time and details can be trusted (0.0-1.0).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ParseConfidence {
+    pub score: f32,
+    pub factors: Vec<ConfidenceFactor>,
+}
+
+impl ParseConfidence {
+    /// Score a fixture from its parse metadata and placeholder fields.
+    pub fn for_fixture(fixture: &Fixture) -> Self {
+        let metadata = &fixture.parse_metadata;
+        let mut factors = Vec::new();
+        let mut penalise = |reason: String, penalty: f32| {
+            factors.push(ConfidenceFactor { reason, penalty });
+        };
+
+        match &metadata.parsing_strategy {
+            ParsingStrategy::ExactMatch => {}
+            ParsingStrategy::SeasonInference(year) => {
+                penalise(format!("year {year} inferred from season"), 0.05)
+            }
+            ParsingStrategy::RelativeDate(expression) => {
+                penalise(format!("relative date '{expression}'"), 0.05)
+            }
+            ParsingStrategy::WeekdayTolerant => penalise("weekday-tolerant parse".to_string(), 0.1),
+            ParsingStrategy::YearAssumption(year) => penalise(format!("year {year} assumed"), 0.25),
+            ParsingStrategy::TimezoneFallback(tz) => {
+                penalise(format!("fallback timezone {tz}"), 0.3)
+            }
+        }
+
+        if let Some(mismatch) = &metadata.weekday_mismatch {
+            penalise(
+                format!(
+                    "source said {}, date is a {}",
+                    mismatch.claimed_weekday, mismatch.actual_weekday
+                ),
+                0.2,
+            );
+        }
+
+        if metadata.dst_resolution.is_some() {
+            penalise("kick-off at a clock change".to_string(), 0.1);
+        }
+
+        for field in placeholder_fields(fixture) {
+            penalise(format!("placeholder {field}"), field.penalty());
+        }
+
+        let score = factors
+            .iter()
+            .fold(1.0, |score, factor| score * (1.0 - factor.penalty));
+
+        Self { score, factors }
+    }
+
+    /// Score as a whole percentage, for display.
+    pub fn percent(&self) -> u32 {
+        (self.score * 100.0).round() as u32
+    }
+}
+
+impl Default for ParseConfidence {
+    /// Full confidence; used for fixtures saved before scores existed.
+    fn default() -> Self {
+        Self {
+            score: 1.0,
+            factors: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for ParseConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.percent())?;
+        if !self.factors.is_empty() {
+            let reasons: Vec<&str> = self.factors.iter().map(|f| f.reason.as_str()).collect();
+            write!(f, " ({})", reasons.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Confidence levels below which validation raises an issue of each severity.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ConfidenceThresholds {
+    pub warning_below: Option<f32>,
+    pub error_below: Option<f32>,
+    pub critical_below: Option<f32>,
+}
+
+impl ConfidenceThresholds {
+    /// Most severe issue level for a score, or `None` when it clears every threshold.
+    pub fn severity_for(&self, score: f32) -> Option<IssueSeverity> {
+        let below = |threshold: Option<f32>| threshold.is_some_and(|t| score < t);
+
+        if below(self.critical_below) {
+            Some(IssueSeverity::Critical)
+        } else if below(self.error_below) {
+            Some(IssueSeverity::Error)
+        } else if below(self.warning_below) {
+            Some(IssueSeverity::Warning)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for ConfidenceThresholds {
+    /// Warn below 75%, error below 50%; never reject outright on confidence alone.
+    fn default() -> Self {
+        Self {
+            warning_below: Some(0.75),
+            error_below: Some(0.5),
+            critical_below: None,
+        }
+    }
+}
+
+/// Fields the scraper recorded as fallbacks, plus any that still read as placeholders.
+pub(crate) fn placeholder_fields(fixture: &Fixture) -> Vec<FixtureField> {
+    let is_placeholder = |value: &str| value.contains("TBD") || value.contains("Unknown");
+    let mut fields = fixture.parse_metadata.field_fallbacks.clone();
+
+    for (field, value) in [
+        (FixtureField::Opponent, &fixture.opponent),
+        (FixtureField::Venue, &fixture.venue),
+        (FixtureField::Competition, &fixture.competition),
+    ] {
+        if is_placeholder(value) && !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::WeekdayMismatch;
+    use crate::test_support::fixture;
+
+    #[test]
+    fn test_exact_parse_is_fully_confident() {
+        let confidence = ParseConfidence::for_fixture(&fixture().venue("Emirates Stadium").build());
+        assert_eq!(confidence.score, 1.0);
+        assert_eq!(confidence.to_string(), "100%");
+    }
+
+    #[test]
+    fn test_penalties_combine() {
+        let mut fixture = fixture().venue("TBD Venue").build();
+        fixture.parse_metadata.parsing_strategy = ParsingStrategy::WeekdayTolerant;
+        fixture.parse_metadata.weekday_mismatch = Some(WeekdayMismatch {
+            claimed_weekday: "Sun".to_string(),
+            actual_weekday: "Saturday".to_string(),
+            date: "Aug 16".to_string(),
+        });
+
+        let confidence = ParseConfidence::for_fixture(&fixture);
+        // 0.9 (tolerant) * 0.8 (mismatch) * 0.9 (venue)
+        assert!((confidence.score - 0.648).abs() < 1e-6);
+        assert_eq!(confidence.factors.len(), 3);
+        assert_eq!(confidence.percent(), 65);
+    }
+
+    #[test]
+    fn test_recorded_fallbacks_are_not_double_counted() {
+        let mut fixture = fixture().venue("TBD Venue").build();
+        fixture.parse_metadata.field_fallbacks = vec![FixtureField::Venue];
+
+        assert_eq!(ParseConfidence::for_fixture(&fixture).factors.len(), 1);
+    }
+
+    #[test]
+    fn test_thresholds_map_to_severity() {
+        let thresholds = ConfidenceThresholds::default();
+        assert_eq!(thresholds.severity_for(0.9), None);
+        assert_eq!(thresholds.severity_for(0.7), Some(IssueSeverity::Warning));
+        assert_eq!(thresholds.severity_for(0.3), Some(IssueSeverity::Error));
+
+        let strict = ConfidenceThresholds {
+            critical_below: Some(0.4),
+            ..thresholds
+        };
+        assert_eq!(strict.severity_for(0.3), Some(IssueSeverity::Critical));
+    }
+}
@@ -0,0 +1,389 @@
+//! # CSV and TSV Export
+//!
+//! For friends who plan in spreadsheets: a header row and one row per
+//! fixture, with the columns chosen by [`CsvExporter::with_columns`]. Dates
+//! and times are local to the exporter's timezone (London by default).
+//!
+//! Quoting follows RFC 4180 for both CSV and TSV, which is what Excel,
+//! LibreOffice and Google Sheets expect: a field containing the delimiter, a
+//! quote or a line break is wrapped in quotes, with quotes doubled. Text
+//! starting with `=`, `+`, `-` or `@` gets a leading `'` so spreadsheets show
+//! it rather than evaluating it as a formula.
+//!
+//! Every fixture is written, including invalid and historical ones; the
+//! `validation` and `issues` columns say which rows need care.
+
+use crate::confidence::{placeholder_fields, FixtureField};
+use crate::dedupe::normalise_team;
+use crate::validation::ValidatedFixture;
+use crate::{Exporter, Fixture, ScrapeError};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Columns written when none are chosen.
+pub const DEFAULT_COLUMNS: [CsvColumn; 10] = [
+    CsvColumn::Date,
+    CsvColumn::Time,
+    CsvColumn::Team,
+    CsvColumn::Opponent,
+    CsvColumn::HomeAway,
+    CsvColumn::Venue,
+    CsvColumn::Competition,
+    CsvColumn::Status,
+    CsvColumn::Validation,
+    CsvColumn::Issues,
+];
+
+/// A spreadsheet column.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvColumn {
+    /// Local date, "2025-08-16"
+    Date,
+    /// Local kick-off, "15:00"
+    Time,
+    /// Kick-off in UTC, RFC 3339
+    Utc,
+    /// Team including the squad, "Arsenal Women"
+    Team,
+    Opponent,
+    /// "Home", "Away", or empty when the team's grounds aren't known
+    HomeAway,
+    Venue,
+    Competition,
+    Sport,
+    /// Lifecycle status, "Postponed"
+    Status,
+    /// "Valid", "Valid with warnings", "Invalid" or "Historical"
+    Validation,
+    /// Issue messages separated by "; "
+    Issues,
+    /// Parse confidence as a percentage
+    Confidence,
+    /// First page the fixture was read from
+    Url,
+}
+
+/// Writes fixtures as CSV or TSV.
+#[derive(Debug, Clone)]
+pub struct CsvExporter {
+    /// `,` for CSV, `\t` for TSV
+    pub delimiter: char,
+    /// Timezone for the date and time columns
+    pub timezone: Tz,
+    pub columns: Vec<CsvColumn>,
+    /// Normalised team name → normalised home venues
+    home_grounds: HashMap<String, Vec<String>>,
+}
+
+impl CsvColumn {
+    pub const ALL: [CsvColumn; 14] = [
+        CsvColumn::Date,
+        CsvColumn::Time,
+        CsvColumn::Utc,
+        CsvColumn::Team,
+        CsvColumn::Opponent,
+        CsvColumn::HomeAway,
+        CsvColumn::Venue,
+        CsvColumn::Competition,
+        CsvColumn::Sport,
+        CsvColumn::Status,
+        CsvColumn::Validation,
+        CsvColumn::Issues,
+        CsvColumn::Confidence,
+        CsvColumn::Url,
+    ];
+
+    /// Name used in configs and on the command line, e.g. "home_away".
+    pub fn name(&self) -> &'static str {
+        match self {
+            CsvColumn::Date => "date",
+            CsvColumn::Time => "time",
+            CsvColumn::Utc => "utc",
+            CsvColumn::Team => "team",
+            CsvColumn::Opponent => "opponent",
+            CsvColumn::HomeAway => "home_away",
+            CsvColumn::Venue => "venue",
+            CsvColumn::Competition => "competition",
+            CsvColumn::Sport => "sport",
+            CsvColumn::Status => "status",
+            CsvColumn::Validation => "validation",
+            CsvColumn::Issues => "issues",
+            CsvColumn::Confidence => "confidence",
+            CsvColumn::Url => "url",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, ScrapeError> {
+        let wanted = name.trim().to_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|column| column.name() == wanted)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(CsvColumn::name).collect();
+                ScrapeError::Parse(format!(
+                    "Unknown column '{name}'; expected one of {}",
+                    names.join(", ")
+                ))
+            })
+    }
+
+    fn header(&self, timezone: Tz) -> String {
+        match self {
+            CsvColumn::Date => format!("Date ({})", timezone.name()),
+            CsvColumn::Time => format!("Time ({})", timezone.name()),
+            CsvColumn::Utc => "Kick-off (UTC)".to_string(),
+            CsvColumn::Team => "Team".to_string(),
+            CsvColumn::Opponent => "Opponent".to_string(),
+            CsvColumn::HomeAway => "Home/Away".to_string(),
+            CsvColumn::Venue => "Venue".to_string(),
+            CsvColumn::Competition => "Competition".to_string(),
+            CsvColumn::Sport => "Sport".to_string(),
+            CsvColumn::Status => "Status".to_string(),
+            CsvColumn::Validation => "Validation".to_string(),
+            CsvColumn::Issues => "Issues".to_string(),
+            CsvColumn::Confidence => "Confidence (%)".to_string(),
+            CsvColumn::Url => "URL".to_string(),
+        }
+    }
+}
+
+impl Default for CsvExporter {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            timezone: chrono_tz::Europe::London,
+            columns: DEFAULT_COLUMNS.to_vec(),
+            home_grounds: HashMap::new(),
+        }
+    }
+}
+
+impl CsvExporter {
+    /// Comma-separated, London time, [`DEFAULT_COLUMNS`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tab-separated, otherwise as [`CsvExporter::new`].
+    pub fn tsv() -> Self {
+        Self::default().with_delimiter('\t')
+    }
+
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    pub fn with_columns(mut self, columns: Vec<CsvColumn>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// A venue `team` plays home fixtures at; every squad of the club shares it.
+    pub fn with_home_ground(mut self, team: &str, venue: &str) -> Self {
+        self.home_grounds
+            .entry(normalise_team(team))
+            .or_default()
+            .push(normalise_team(venue));
+        self
+    }
+
+    /// "Home" or "Away"; `None` while the venue or the team's grounds are unknown.
+    pub fn home_or_away(&self, fixture: &Fixture) -> Option<&'static str> {
+        if placeholder_fields(fixture).contains(&FixtureField::Venue) {
+            return None;
+        }
+        let grounds = self.home_grounds.get(&normalise_team(&fixture.team))?;
+        if grounds.contains(&normalise_team(&fixture.venue)) {
+            Some("Home")
+        } else {
+            Some("Away")
+        }
+    }
+
+    fn cell(&self, column: CsvColumn, validated: &ValidatedFixture) -> String {
+        let fixture = &validated.fixture;
+        let local = fixture.datetime.with_timezone(&self.timezone);
+
+        match column {
+            CsvColumn::Date => local.format("%Y-%m-%d").to_string(),
+            CsvColumn::Time => local.format("%H:%M").to_string(),
+            CsvColumn::Utc => fixture.datetime.to_rfc3339(),
+            CsvColumn::Team => fixture.team_label(),
+            CsvColumn::Opponent => fixture.opponent.clone(),
+            CsvColumn::HomeAway => self.home_or_away(fixture).unwrap_or_default().to_string(),
+            CsvColumn::Venue => fixture.venue.clone(),
+            CsvColumn::Competition => fixture.competition.clone(),
+            CsvColumn::Sport => fixture.sport.to_string(),
+            CsvColumn::Status => fixture.status.to_string(),
+            CsvColumn::Validation => validated.validation.label().to_string(),
+            CsvColumn::Issues => validated
+                .validation
+                .issues()
+                .iter()
+                .map(|issue| issue.message.as_str())
+                .collect::<Vec<_>>()
+                .join("; "),
+            CsvColumn::Confidence => validated.confidence.percent().to_string(),
+            CsvColumn::Url => fixture
+                .sources
+                .first()
+                .map(|source| source.url.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn row(&self, fields: impl Iterator<Item = String>) -> String {
+        let fields: Vec<String> = fields.map(|field| self.quote(&field)).collect();
+        fields.join(&self.delimiter.to_string()) + "\r\n"
+    }
+
+    fn quote(&self, field: &str) -> String {
+        let field = if field.starts_with(['=', '+', '-', '@']) {
+            format!("'{field}")
+        } else {
+            field.to_string()
+        };
+
+        if field.contains([self.delimiter, '"', '\r', '\n']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field
+        }
+    }
+}
+
+impl Exporter for CsvExporter {
+    /// Header and one row per fixture, with CRLF line endings.
+    fn export(&self, fixtures: &[ValidatedFixture], _now: DateTime<Utc>) -> String {
+        let mut output = self.row(
+            self.columns
+                .iter()
+                .map(|column| column.header(self.timezone)),
+        );
+        for validated in fixtures {
+            output.push_str(
+                &self.row(
+                    self.columns
+                        .iter()
+                        .map(|&column| self.cell(column, validated)),
+                ),
+            );
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status::FixtureStatus;
+    use crate::test_support::{fixture, now};
+    use crate::validation::{FixtureValidation, IssueCategory, IssueSeverity, ValidationIssue};
+
+    fn validated(opponent: &str, venue: &str) -> ValidatedFixture {
+        fixture().opponent(opponent).venue(venue).validated()
+    }
+
+    fn warning(message: &str) -> ValidationIssue {
+        ValidationIssue {
+            severity: IssueSeverity::Warning,
+            category: IssueCategory::DataInconsistency,
+            message: message.to_string(),
+            suggested_fix: None,
+        }
+    }
+
+    #[test]
+    fn test_default_columns() {
+        let csv = CsvExporter::new()
+            .with_home_ground("Arsenal", "Emirates Stadium")
+            .export(&[validated("Chelsea", "Emirates Stadium")], now());
+        let rows: Vec<&str> = csv.split("\r\n").collect();
+
+        assert_eq!(
+            rows[0],
+            "Date (Europe/London),Time (Europe/London),Team,Opponent,Home/Away,Venue,Competition,Status,Validation,Issues"
+        );
+        assert_eq!(
+            rows[1],
+            "2025-08-16,15:00,Arsenal,Chelsea,Home,Emirates Stadium,Premier League,Scheduled,Valid,"
+        );
+        assert_eq!(rows[2], "");
+    }
+
+    #[test]
+    fn test_quoting() {
+        let mut tricky = validated("Chelsea", "Stamford Bridge, \"The Bridge\"");
+        tricky.validation = FixtureValidation::ValidWithWarnings(vec![
+            warning("Kick-off\ttime unconfirmed"),
+            warning("=HYPERLINK(\"x\")"),
+        ]);
+        let exporter = CsvExporter::new().with_columns(vec![CsvColumn::Venue, CsvColumn::Issues]);
+
+        let csv = exporter.export(std::slice::from_ref(&tricky), now());
+        assert!(csv.ends_with(
+            "\"Stamford Bridge, \"\"The Bridge\"\"\",\"Kick-off\ttime unconfirmed; =HYPERLINK(\"\"x\"\")\"\r\n"
+        ));
+
+        let tsv = CsvExporter::tsv()
+            .with_columns(vec![CsvColumn::Issues, CsvColumn::Validation])
+            .export(&[tricky], now());
+        assert!(tsv.ends_with(
+            "\"Kick-off\ttime unconfirmed; =HYPERLINK(\"\"x\"\")\"\tValid with warnings\r\n"
+        ));
+
+        assert_eq!(CsvExporter::new().quote("=1+1"), "'=1+1");
+        assert_eq!(CsvExporter::new().quote("-"), "'-");
+    }
+
+    #[test]
+    fn test_home_or_away() {
+        let home = validated("Chelsea", "Emirates Stadium");
+        assert_eq!(CsvExporter::new().home_or_away(&home.fixture), None);
+
+        let exporter = CsvExporter::new()
+            .with_home_ground("Arsenal", "Emirates Stadium")
+            .with_home_ground("Arsenal", "Meadow Park");
+        let away = validated("Newcastle United", "St James' Park");
+        let unknown = validated("Chelsea", "TBD Venue");
+        assert_eq!(exporter.home_or_away(&home.fixture), Some("Home"));
+        assert_eq!(exporter.home_or_away(&away.fixture), Some("Away"));
+        assert_eq!(exporter.home_or_away(&unknown.fixture), None);
+
+        let mut springboks = away.fixture.clone();
+        springboks.team = "South Africa".to_string();
+        springboks.venue = "Ellis Park".to_string();
+        assert_eq!(exporter.home_or_away(&springboks), None);
+        let exporter = exporter.with_home_ground("South Africa", "Ellis Park");
+        assert_eq!(exporter.home_or_away(&springboks), Some("Home"));
+    }
+
+    #[test]
+    fn test_columns_and_timezone() {
+        let columns: Vec<CsvColumn> = ["date", "Time", "utc", "home-away", "status"]
+            .into_iter()
+            .map(|name| CsvColumn::from_name(name).unwrap())
+            .collect();
+        assert!(CsvColumn::from_name("kickoff").is_err());
+
+        let mut postponed = validated("Chelsea", "Emirates Stadium");
+        postponed.fixture.status = FixtureStatus::Postponed;
+        let csv = CsvExporter::new()
+            .with_home_ground("Arsenal", "Emirates Stadium")
+            .with_columns(columns)
+            .with_timezone(chrono_tz::America::New_York)
+            .export(&[postponed], now());
+
+        assert!(csv.starts_with("Date (America/New_York),Time (America/New_York),"));
+        assert!(csv.ends_with("2025-08-16,10:00,2025-08-16T14:00:00+00:00,Home,Postponed\r\n"));
+    }
+}
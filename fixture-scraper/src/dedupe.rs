@@ -0,0 +1,271 @@
+//! # Duplicate Fixture Detection
+//!
+//! The same match can reach us more than once: a club page listing it in two
+//! sections, two scrapers covering the same squad, or both clubs' own feeds
+//! ("Arsenal vs Chelsea" and "Chelsea vs Arsenal"). [`Deduplicator`] groups
+//! fixtures describing the same match and merges each group into one fixture.
+//!
+//! ## Same match
+//!
+//! - Same sport
+//! - Same two teams after normalisation ("Chelsea FC" = "chelsea"), in either
+//!   order; a placeholder opponent matches any opponent of the same team
+//! - Kick-offs within the tolerance (two hours by default), so a feed that got
+//!   the clock change wrong still lines up
+//! - Same competition, unless either side only has a placeholder
+//!
+//! ## Merging
+//!
+//! Fixtures in a group are ranked by parse confidence, then by fewest
+//! placeholder fields, then by input order. The top-ranked fixture supplies
+//! the kick-off and metadata; placeholder fields and missing details are
+//! filled from the next-ranked fixture that has them. Every contributing
+//! source is kept in `Fixture::sources`.
+
+use crate::confidence::{placeholder_fields, FixtureField, ParseConfidence};
+use crate::Fixture;
+use chrono::Duration;
+
+/// Finds and merges fixtures that describe the same match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Deduplicator {
+    pub kick_off_tolerance: Duration,
+}
+
+impl Default for Deduplicator {
+    fn default() -> Self {
+        Self {
+            kick_off_tolerance: Duration::hours(2),
+        }
+    }
+}
+
+impl Deduplicator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How far apart two kick-offs may be and still be the same match.
+    pub fn with_kick_off_tolerance(mut self, tolerance: Duration) -> Self {
+        self.kick_off_tolerance = tolerance;
+        self
+    }
+
+    /// Whether two fixtures describe the same match.
+    pub fn is_same_match(&self, a: &Fixture, b: &Fixture) -> bool {
+        a.sport == b.sport
+            && (a.datetime - b.datetime).abs() <= self.kick_off_tolerance
+            && same_teams(a, b)
+            && same_competition(a, b)
+    }
+
+    /// One fixture per real match, in order of each match's first appearance.
+    pub fn dedupe(&self, fixtures: Vec<Fixture>) -> Vec<Fixture> {
+        let mut groups: Vec<Vec<Fixture>> = Vec::new();
+
+        for fixture in fixtures {
+            match groups
+                .iter_mut()
+                .find(|group| group.iter().any(|seen| self.is_same_match(seen, &fixture)))
+            {
+                Some(group) => group.push(fixture),
+                None => groups.push(vec![fixture]),
+            }
+        }
+
+        groups.into_iter().map(merge).collect()
+    }
+}
+
+/// Lowercase, drop punctuation and club suffixes ("FC", "AFC") so feeds agree on names.
+pub fn normalise_team(name: &str) -> String {
+    name.to_lowercase()
+        .replace('&', " and ")
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .filter(|word| !matches!(*word, "fc" | "afc" | "cf" | "sc" | "the"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn same_teams(a: &Fixture, b: &Fixture) -> bool {
+    let (a_team, a_opponent) = (normalise_team(&a.team_label()), normalise_team(&a.opponent));
+    let (b_team, b_opponent) = (normalise_team(&b.team_label()), normalise_team(&b.opponent));
+
+    // Same feed orientation; an undecided opponent matches the decided one
+    if a_team == b_team {
+        return a_opponent == b_opponent
+            || has_placeholder(a, FixtureField::Opponent)
+            || has_placeholder(b, FixtureField::Opponent);
+    }
+
+    // The other club's feed lists the fixture the other way round
+    a_team == b_opponent && a_opponent == b_team
+}
+
+fn same_competition(a: &Fixture, b: &Fixture) -> bool {
+    has_placeholder(a, FixtureField::Competition)
+        || has_placeholder(b, FixtureField::Competition)
+        || normalise_team(&a.competition) == normalise_team(&b.competition)
+}
+
+fn has_placeholder(fixture: &Fixture, field: FixtureField) -> bool {
+    placeholder_fields(fixture).contains(&field)
+}
+
+/// Merge a group of duplicates using the field-precedence rule in the module docs.
+fn merge(group: Vec<Fixture>) -> Fixture {
+    let mut ranked: Vec<(usize, f32, usize)> = group
+        .iter()
+        .enumerate()
+        .map(|(index, fixture)| {
+            let score = ParseConfidence::for_fixture(fixture).score;
+            (index, score, placeholder_fields(fixture).len())
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)).then(a.0.cmp(&b.0)));
+    let ranked: Vec<&Fixture> = ranked.iter().map(|(index, _, _)| &group[*index]).collect();
+
+    let mut merged = ranked[0].clone();
+    let merged_team = normalise_team(&merged.team_label());
+    let same_orientation = |other: &Fixture| normalise_team(&other.team_label()) == merged_team;
+
+    for field in placeholder_fields(&merged) {
+        let donor = ranked[1..].iter().find(|other| {
+            !has_placeholder(other, field)
+                && (field != FixtureField::Opponent || same_orientation(other))
+        });
+        let Some(donor) = donor else {
+            continue;
+        };
+
+        match field {
+            FixtureField::Opponent => merged.opponent = donor.opponent.clone(),
+            FixtureField::Venue => merged.venue = donor.venue.clone(),
+            FixtureField::Competition => merged.competition = donor.competition.clone(),
+        }
+        merged
+            .parse_metadata
+            .field_fallbacks
+            .retain(|f| *f != field);
+    }
+
+    if merged.sport_details.is_none() {
+        merged.sport_details = ranked.iter().find_map(|f| f.sport_details.clone());
+    }
+    if merged.expected_duration.is_none() {
+        merged.expected_duration = ranked.iter().find_map(|f| f.expected_duration);
+    }
+
+    merged.sources = Vec::new();
+    for source in group.iter().flat_map(|fixture| &fixture.sources) {
+        if !merged.sources.contains(source) {
+            merged.sources.push(source.clone());
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::ParsingStrategy;
+    use crate::test_support::{self, utc};
+    use crate::FixtureSource;
+
+    fn fixture(team: &str, opponent: &str, hour: u32, venue: &str) -> Fixture {
+        test_support::fixture()
+            .team(team)
+            .opponent(opponent)
+            .venue(venue)
+            .at(utc(2025, 8, 23, hour, 30))
+            .build()
+    }
+
+    #[test]
+    fn test_normalise_team() {
+        assert_eq!(normalise_team("Chelsea FC"), "chelsea");
+        assert_eq!(
+            normalise_team("Brighton & Hove Albion"),
+            "brighton and hove albion"
+        );
+        assert_eq!(normalise_team("AFC Bournemouth"), "bournemouth");
+    }
+
+    #[test]
+    fn test_same_page_duplicates_merge() {
+        let page = "https://www.arsenal.com/fixtures";
+        let teaser = fixture("Arsenal", "Leeds United", 16, "Emirates Stadium")
+            .with_source(FixtureSource::new("Arsenal", page).with_section("teaser"));
+        let accordion = fixture("Arsenal", "Leeds United", 16, "Emirates Stadium")
+            .with_source(FixtureSource::new("Arsenal", page).with_section("accordion"));
+        let other = fixture("Arsenal", "Liverpool", 16, "Anfield");
+
+        let deduped = Deduplicator::new().dedupe(vec![teaser, other, accordion]);
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].opponent, "Leeds United");
+        assert_eq!(deduped[0].sources.len(), 2);
+        assert_eq!(deduped[1].opponent, "Liverpool");
+    }
+
+    #[test]
+    fn test_opponent_feed_merges_with_precedence() {
+        // Our feed is exact but has no venue; theirs is an hour off and less certain
+        let mut ours = fixture("Arsenal", "Chelsea", 16, "TBD Venue").with_source(
+            FixtureSource::new("Arsenal", "https://www.arsenal.com/fixtures"),
+        );
+        ours.parse_metadata.field_fallbacks = vec![FixtureField::Venue];
+
+        let mut theirs = fixture("Chelsea FC", "Arsenal", 17, "Stamford Bridge").with_source(
+            FixtureSource::new("Chelsea", "https://www.chelseafc.com/fixtures"),
+        );
+        theirs.parse_metadata.parsing_strategy = ParsingStrategy::YearAssumption(2025);
+
+        let deduped = Deduplicator::new().dedupe(vec![theirs.clone(), ours.clone()]);
+        assert_eq!(deduped.len(), 1);
+
+        let merged = &deduped[0];
+        assert_eq!(merged.team, "Arsenal");
+        assert_eq!(merged.datetime, ours.datetime);
+        assert_eq!(merged.venue, "Stamford Bridge");
+        assert!(merged.parse_metadata.field_fallbacks.is_empty());
+        assert_eq!(merged.sources[0].name, "Chelsea");
+        assert_eq!(merged.sources.len(), 2);
+
+        // Same winner whichever order the feeds arrive in
+        let reversed = Deduplicator::new().dedupe(vec![ours, theirs]);
+        assert_eq!(reversed[0].datetime, merged.datetime);
+        assert_eq!(reversed[0].venue, merged.venue);
+    }
+
+    #[test]
+    fn test_distinct_matches_stay_apart() {
+        let league = fixture("Arsenal", "Chelsea", 16, "Emirates Stadium");
+        let mut cup = league.clone();
+        cup.competition = "FA Cup".to_string();
+        let mut rematch = league.clone();
+        rematch.datetime += Duration::days(14);
+        let women = league.clone().with_squad(crate::Squad::Women);
+
+        let deduper = Deduplicator::new();
+        assert!(!deduper.is_same_match(&league, &cup));
+        assert!(!deduper.is_same_match(&league, &rematch));
+        assert!(!deduper.is_same_match(&league, &women));
+        assert_eq!(deduper.dedupe(vec![league, cup, rematch, women]).len(), 4);
+    }
+
+    #[test]
+    fn test_placeholder_opponent_matches_drawn_opponent() {
+        let mut tbd = fixture("Arsenal", "TBD Opponent", 19, "Emirates Stadium");
+        tbd.competition = "Carabao Cup".to_string();
+        let mut drawn = tbd.clone();
+        drawn.opponent = "Port Vale".to_string();
+
+        let deduped = Deduplicator::new().dedupe(vec![tbd, drawn]);
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].opponent, "Port Vale");
+    }
+}
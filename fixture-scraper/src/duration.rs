@@ -0,0 +1,238 @@
+//! # Expected Event Durations
+//!
+//! Sources only publish a start time, but a calendar event needs an end and
+//! clash detection needs the whole window. A cup tie that might go to extra
+//! time, a rugby test, a five-day cricket Test and an F1 race all occupy very
+//! different slots, so durations are derived in three layers:
+//!
+//! 1. **Source override**: a scraper that knows better sets `Fixture::expected_duration`
+//! 2. **Competition defaults**: keyword rules such as "Cup" marking knockout ties
+//! 3. **Sport defaults**: a sensible slot per sport (and per cricket format / F1 session)
+//!
+//! Scrapers fill in `expected_duration` from their configured [`DurationDefaults`]
+//! (e.g. `ArsenalScraper::with_durations`), so calendar ends, clash detection and
+//! the planner all see the same slot. Fixtures saved without one fall back to
+//! [`DurationDefaults::standard`].
+//!
+//! Durations are slot lengths, so they include half-time and typical stoppages.
+
+use crate::sport::{CricketFormat, MotorsportSession, Sport, SportDetails};
+use crate::Fixture;
+use serde::{Deserialize, Serialize};
+
+/// How long an event is expected to take, in whole minutes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExpectedDuration {
+    /// Regular slot length including breaks
+    pub minutes: u32,
+    /// Extra minutes if the tie can go to extra time (and penalties); 0 when it can't
+    #[serde(default)]
+    pub extra_time_minutes: u32,
+}
+
+impl ExpectedDuration {
+    /// A fixed-length event with no extra time.
+    pub const fn regular(minutes: u32) -> Self {
+        Self {
+            minutes,
+            extra_time_minutes: 0,
+        }
+    }
+
+    /// Allow for extra time on top of the regular slot (knockout ties).
+    pub const fn with_extra_time(mut self, extra_time_minutes: u32) -> Self {
+        self.extra_time_minutes = extra_time_minutes;
+        self
+    }
+
+    /// Whether the event might run into extra time.
+    pub fn may_go_to_extra_time(&self) -> bool {
+        self.extra_time_minutes > 0
+    }
+
+    /// Longest the event is expected to run, extra time included.
+    pub fn total_minutes(&self) -> u32 {
+        self.minutes + self.extra_time_minutes
+    }
+
+    /// Default slot for a sport, refined by its sport-specific details.
+    pub fn for_sport(sport: Sport, details: Option<&SportDetails>) -> Self {
+        match (sport, details) {
+            (Sport::Cricket, Some(SportDetails::Cricket { format })) => match format {
+                // Close of play on the last scheduled day
+                CricketFormat::MultiDay { days } => {
+                    Self::regular(u32::from(days.saturating_sub(1)) * 24 * 60 + 7 * 60 + 30)
+                }
+                CricketFormat::OneDay => Self::regular(8 * 60),
+                CricketFormat::T20 => Self::regular(3 * 60 + 30),
+            },
+            (Sport::Motorsport, Some(SportDetails::Motorsport { session, .. })) => match session {
+                MotorsportSession::Practice(_) | MotorsportSession::Qualifying => Self::regular(60),
+                MotorsportSession::SprintQualifying => Self::regular(45),
+                MotorsportSession::Sprint => Self::regular(60),
+                MotorsportSession::Race => Self::regular(2 * 60),
+            },
+            (Sport::Football, _) => Self::regular(115),
+            (Sport::RugbyUnion, _) => Self::regular(2 * 60),
+            (Sport::RugbyLeague, _) => Self::regular(110),
+            // Assume a Test when the format is unknown: better too long than a surprise clash
+            (Sport::Cricket, _) => Self::for_sport(
+                Sport::Cricket,
+                Some(&SportDetails::Cricket {
+                    format: CricketFormat::TEST,
+                }),
+            ),
+            (Sport::Motorsport, _) => Self::regular(2 * 60),
+        }
+    }
+}
+
+/// Competition keyword rule, e.g. every football "Cup" tie may need extra time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompetitionDuration {
+    /// Matched by [`Fixture::competition_matches`]
+    pub keyword: String,
+    /// Restrict the rule to one sport (`None` = any sport)
+    pub sport: Option<Sport>,
+    /// Replacement slot length (`None` keeps the sport default)
+    pub minutes: Option<u32>,
+    /// Extra time allowance for knockout ties
+    pub extra_time_minutes: u32,
+}
+
+/// Sport and competition defaults used when a source gives no duration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DurationDefaults {
+    pub competitions: Vec<CompetitionDuration>,
+}
+
+impl DurationDefaults {
+    /// Built-in knockout rules: football cup ties and finals can go to
+    /// extra time and penalties, rugby finals to extra time.
+    pub fn standard() -> Self {
+        let football_knockout = |keyword: &str| CompetitionDuration {
+            keyword: keyword.to_string(),
+            sport: Some(Sport::Football),
+            minutes: None,
+            extra_time_minutes: 50, // 30 minutes, a break, then penalties
+        };
+
+        Self {
+            competitions: vec![
+                football_knockout("Cup"),
+                football_knockout("Final"),
+                football_knockout("Play-Off"),
+                CompetitionDuration {
+                    keyword: "Final".to_string(),
+                    sport: Some(Sport::RugbyUnion),
+                    minutes: None,
+                    extra_time_minutes: 25,
+                },
+            ],
+        }
+    }
+
+    /// Add or prepend a competition rule; newer rules win over built-in ones.
+    pub fn with_competition(mut self, rule: CompetitionDuration) -> Self {
+        self.competitions.insert(0, rule);
+        self
+    }
+
+    /// Expected duration for a fixture, ignoring any per-source override.
+    pub fn for_fixture(&self, fixture: &Fixture) -> ExpectedDuration {
+        let base = ExpectedDuration::for_sport(fixture.sport, fixture.sport_details.as_ref());
+
+        self.competitions
+            .iter()
+            .filter(|rule| rule.sport.is_none_or(|sport| sport == fixture.sport))
+            .find(|rule| fixture.competition_matches(&rule.keyword))
+            .map(|rule| ExpectedDuration {
+                minutes: rule.minutes.unwrap_or(base.minutes),
+                extra_time_minutes: rule.extra_time_minutes,
+            })
+            .unwrap_or(base)
+    }
+}
+
+impl Default for DurationDefaults {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sport::{MotorsportSession, RugbyCode, RugbyMatchType};
+    use crate::test_support::fixture;
+
+    #[test]
+    fn test_league_match_has_no_extra_time() {
+        let duration = DurationDefaults::standard()
+            .for_fixture(&fixture().competition("Premier League").build());
+        assert_eq!(duration.minutes, 115);
+        assert!(!duration.may_go_to_extra_time());
+    }
+
+    #[test]
+    fn test_cup_tie_may_go_to_extra_time() {
+        let duration =
+            DurationDefaults::standard().for_fixture(&fixture().competition("FA Cup").build());
+        assert!(duration.may_go_to_extra_time());
+        assert_eq!(duration.total_minutes(), 165);
+    }
+
+    #[test]
+    fn test_sport_specific_defaults() {
+        let rugby = fixture()
+            .competition("Rugby Championship")
+            .build()
+            .with_sport_details(SportDetails::Rugby {
+                code: RugbyCode::Union,
+                match_type: RugbyMatchType::Test,
+            });
+        assert_eq!(
+            DurationDefaults::standard().for_fixture(&rugby).minutes,
+            120
+        );
+
+        // A five-day Test finishes at close of play on day five
+        let cricket = fixture()
+            .competition("The Ashes")
+            .build()
+            .with_sport_details(SportDetails::Cricket {
+                format: CricketFormat::TEST,
+            });
+        assert_eq!(
+            DurationDefaults::standard().for_fixture(&cricket).minutes,
+            4 * 24 * 60 + 450
+        );
+
+        let qualifying = fixture()
+            .competition("Formula 1")
+            .build()
+            .with_sport_details(SportDetails::Motorsport {
+                series: "Formula 1".to_string(),
+                session: MotorsportSession::Qualifying,
+            });
+        assert_eq!(
+            DurationDefaults::standard()
+                .for_fixture(&qualifying)
+                .minutes,
+            60
+        );
+    }
+
+    #[test]
+    fn test_custom_competition_rule_wins() {
+        let defaults = DurationDefaults::standard().with_competition(CompetitionDuration {
+            keyword: "League Cup".to_string(),
+            sport: Some(Sport::Football),
+            minutes: None,
+            extra_time_minutes: 15, // straight to penalties
+        });
+
+        let duration = defaults.for_fixture(&fixture().competition("Carabao League Cup").build());
+        assert_eq!(duration.extra_time_minutes, 15);
+    }
+}
@@ -0,0 +1,563 @@
+//! # iCalendar Export
+//!
+//! Turns validated fixtures into an RFC 5545 calendar that Google Calendar,
+//! Outlook and Apple Calendar can subscribe to. Each usable fixture becomes a
+//! `VEVENT`:
+//!
+//! - `UID` stays the same across scrapes and reschedules (see [`IcsExporter::uid`]);
+//!   fixtures that would share one get their kick-off time added
+//! - `DTSTART`/`DTEND` are wall-clock times in the calendar's timezone, with
+//!   a matching `VTIMEZONE` embedded so clients don't need their own tz data
+//! - `SUMMARY`, `LOCATION` and `DESCRIPTION` are rendered from an
+//!   [`EventTemplate`] (the `verbose` preset unless one is set); `LOCATION` is
+//!   left out when the template renders it empty
+//! - `STATUS` is `CANCELLED` for called-off fixtures, so the event is struck
+//!   through rather than silently disappearing, and `TENTATIVE` while the
+//!   opponent is still to be decided
+//! - `SEQUENCE` comes from [`IcsExporter::with_sequence`]; the CLI takes it
+//!   from the fixture store's version history so clients pick up changes
+//! - `VALARM` reminders come from a [`ReminderPolicy`], if one is set
+//!
+//! Invalid and historical fixtures are left out.
+
+use crate::confidence::{placeholder_fields, FixtureField};
+use crate::dedupe::normalise_team;
+use crate::reminders::ReminderPolicy;
+use crate::schema::SCRAPER_VERSION;
+use crate::status::FixtureStatus;
+use crate::templates::EventTemplate;
+use crate::validation::ValidatedFixture;
+use crate::{Exporter, Fixture};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+use std::collections::HashMap;
+
+/// Content lines longer than this many octets are folded (RFC 5545 §3.1).
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Builds `.ics` calendars from validated fixtures.
+#[derive(Debug, Clone)]
+pub struct IcsExporter {
+    /// Timezone for event times and the embedded `VTIMEZONE`
+    pub timezone: Tz,
+    /// Shown by clients as the subscribed calendar's name
+    pub calendar_name: String,
+    pub reminders: ReminderPolicy,
+    pub template: EventTemplate,
+    sequences: HashMap<String, u32>,
+}
+
+impl Default for IcsExporter {
+    fn default() -> Self {
+        Self {
+            timezone: chrono_tz::Europe::London,
+            calendar_name: "CalPal Fixtures".to_string(),
+            reminders: ReminderPolicy::default(),
+            template: EventTemplate::verbose(),
+            sequences: HashMap::new(),
+        }
+    }
+}
+
+impl IcsExporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    pub fn with_calendar_name(mut self, name: &str) -> Self {
+        self.calendar_name = name.to_string();
+        self
+    }
+
+    pub fn with_reminders(mut self, reminders: ReminderPolicy) -> Self {
+        self.reminders = reminders;
+        self
+    }
+
+    pub fn with_template(mut self, template: EventTemplate) -> Self {
+        self.template = template;
+        self
+    }
+
+    /// Revision number for the event with `uid`; 0 when not given.
+    pub fn with_sequence(mut self, uid: &str, sequence: u32) -> Self {
+        self.sequences.insert(uid.to_string(), sequence);
+        self
+    }
+
+    /// Event UID: team, competition and original kick-off date.
+    ///
+    /// The opponent is left out so a cup draw filling in "TBD Opponent"
+    /// updates the same event; a rescheduled fixture keeps the date it was
+    /// first listed for. Motorsport sessions share an event and a date, so
+    /// their session name is included.
+    pub fn uid(fixture: &Fixture) -> String {
+        format!("{}@calpal", uid_stem(fixture))
+    }
+
+    /// The UID of each of `fixtures` when exported together.
+    ///
+    /// Usable fixtures that would share a [`uid`](Self::uid), such as two
+    /// friendlies on the same day, each get their original kick-off time
+    /// added so calendar clients don't merge them.
+    pub fn uids(fixtures: &[ValidatedFixture]) -> Vec<String> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for validated in fixtures.iter().filter(|v| v.is_usable()) {
+            *counts.entry(Self::uid(&validated.fixture)).or_default() += 1;
+        }
+
+        fixtures
+            .iter()
+            .map(|validated| {
+                let fixture = &validated.fixture;
+                let uid = Self::uid(fixture);
+                if validated.is_usable() && counts[&uid] > 1 {
+                    let time = original_kick_off(fixture).format("%H%M");
+                    format!("{}-{time}@calpal", uid_stem(fixture))
+                } else {
+                    uid
+                }
+            })
+            .collect()
+    }
+
+    /// The whole calendar, with CRLF line endings. `now` becomes each event's `DTSTAMP`.
+    pub fn export(&self, fixtures: &[ValidatedFixture], now: DateTime<Utc>) -> String {
+        let uids = Self::uids(fixtures);
+        let usable: Vec<(&ValidatedFixture, &String)> = fixtures
+            .iter()
+            .zip(&uids)
+            .filter(|(v, _)| v.is_usable())
+            .collect();
+
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:-//CalPal//Fixture Scraper {SCRAPER_VERSION}//EN"),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            format!("X-WR-CALNAME:{}", escape_text(&self.calendar_name)),
+            format!("X-WR-TIMEZONE:{}", self.timezone.name()),
+        ];
+
+        let kick_offs = usable.iter().map(|(v, _)| v.fixture.datetime);
+        if let (Some(first), Some(last)) = (kick_offs.clone().min(), kick_offs.max()) {
+            lines.extend(self.vtimezone(first, last));
+        }
+
+        for (validated, uid) in usable {
+            lines.extend(self.vevent(validated, uid, now));
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        lines.iter().map(|line| fold(line) + "\r\n").collect()
+    }
+
+    fn vevent(&self, validated: &ValidatedFixture, uid: &str, now: DateTime<Utc>) -> Vec<String> {
+        let fixture = &validated.fixture;
+        let placeholders = placeholder_fields(fixture);
+
+        let status = if fixture.status.is_called_off() {
+            "CANCELLED"
+        } else if placeholders.contains(&FixtureField::Opponent) {
+            "TENTATIVE"
+        } else {
+            "CONFIRMED"
+        };
+        let event = self.template.render(validated, self.timezone);
+
+        let mut lines = vec![
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{uid}"),
+            format!("DTSTAMP:{}", now.format("%Y%m%dT%H%M%SZ")),
+            format!(
+                "DTSTART;TZID={}:{}",
+                self.timezone.name(),
+                self.local(fixture.datetime)
+            ),
+            format!(
+                "DTEND;TZID={}:{}",
+                self.timezone.name(),
+                self.local(fixture.expected_end())
+            ),
+            format!("SUMMARY:{}", escape_text(&event.title)),
+        ];
+        if !event.location.is_empty() {
+            lines.push(format!("LOCATION:{}", escape_text(&event.location)));
+        }
+        lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+        if let Some(source) = fixture.sources.first() {
+            lines.push(format!("URL:{}", source.url));
+        }
+        lines.push(format!("STATUS:{status}"));
+        lines.push(format!(
+            "SEQUENCE:{}",
+            self.sequences.get(uid).copied().unwrap_or(0)
+        ));
+        for reminder in self.reminders.reminders_for(fixture) {
+            lines.extend([
+                "BEGIN:VALARM".to_string(),
+                "ACTION:DISPLAY".to_string(),
+                format!(
+                    "DESCRIPTION:{}",
+                    escape_text(&reminder.message(fixture, self.timezone))
+                ),
+                format!("TRIGGER:{}", format_trigger(reminder.minutes_before)),
+                "END:VALARM".to_string(),
+            ]);
+        }
+        lines.push("END:VEVENT".to_string());
+
+        lines
+    }
+
+    fn local(&self, datetime: DateTime<Utc>) -> String {
+        datetime
+            .with_timezone(&self.timezone)
+            .format("%Y%m%dT%H%M%S")
+            .to_string()
+    }
+
+    /// `VTIMEZONE` covering the calendar years of `first` to `last`, one
+    /// observance per offset change.
+    fn vtimezone(&self, first: DateTime<Utc>, last: DateTime<Utc>) -> Vec<String> {
+        let timezone = self.timezone;
+        let start = Utc
+            .with_ymd_and_hms(first.year(), 1, 1, 0, 0, 0)
+            .single()
+            .unwrap_or(first);
+        let end = Utc
+            .with_ymd_and_hms(last.year() + 1, 1, 1, 0, 0, 0)
+            .single()
+            .unwrap_or(last);
+        let offset_at =
+            |instant: DateTime<Utc>| timezone.offset_from_utc_datetime(&instant.naive_utc());
+
+        let mut lines = vec![
+            "BEGIN:VTIMEZONE".to_string(),
+            format!("TZID:{}", timezone.name()),
+        ];
+        let initial = offset_at(start);
+        lines.extend(observance(&initial, initial.fix(), start));
+
+        // Day steps find each change; bisection pins it to the minute
+        let mut day = start;
+        while day < end {
+            let next = day + Duration::days(1);
+            if offset_at(day).fix() != offset_at(next).fix() {
+                let (mut before, mut after) = (0, 24 * 60);
+                while after - before > 1 {
+                    let middle = (before + after) / 2;
+                    if offset_at(day + Duration::minutes(middle)).fix() == offset_at(day).fix() {
+                        before = middle;
+                    } else {
+                        after = middle;
+                    }
+                }
+                let after = day + Duration::minutes(after);
+                lines.extend(observance(&offset_at(after), offset_at(day).fix(), after));
+            }
+            day = next;
+        }
+
+        lines.push("END:VTIMEZONE".to_string());
+        lines
+    }
+}
+
+impl Exporter for IcsExporter {
+    fn export(&self, fixtures: &[ValidatedFixture], now: DateTime<Utc>) -> String {
+        IcsExporter::export(self, fixtures, now)
+    }
+}
+
+/// [`IcsExporter::uid`] without the `@calpal` domain.
+fn uid_stem(fixture: &Fixture) -> String {
+    let slug = |name: &str| normalise_team(name).replace(' ', "-");
+    let mut stem = format!(
+        "{}-{}-{}",
+        slug(&fixture.team_label()),
+        slug(&fixture.competition),
+        original_kick_off(fixture).format("%Y%m%d")
+    );
+    if !fixture.sport.is_head_to_head() {
+        stem.push_str(&format!("-{}", slug(&fixture.opponent)));
+    }
+    stem
+}
+
+/// When the fixture was first listed to start, before any reschedule.
+fn original_kick_off(fixture: &Fixture) -> DateTime<Utc> {
+    match fixture.status {
+        FixtureStatus::Rescheduled { previous } => previous,
+        _ => fixture.datetime,
+    }
+}
+
+/// A `STANDARD` or `DAYLIGHT` block for `offset`, taking effect at `onset`.
+fn observance(
+    offset: &chrono_tz::TzOffset,
+    previous: FixedOffset,
+    onset: DateTime<Utc>,
+) -> Vec<String> {
+    let kind = if offset.dst_offset().is_zero() {
+        "STANDARD"
+    } else {
+        "DAYLIGHT"
+    };
+
+    let mut lines = vec![
+        format!("BEGIN:{kind}"),
+        // Onset is written as wall-clock time in the offset being replaced
+        format!(
+            "DTSTART:{}",
+            onset.with_timezone(&previous).format("%Y%m%dT%H%M%S")
+        ),
+        format!("TZOFFSETFROM:{}", format_offset(previous)),
+        format!("TZOFFSETTO:{}", format_offset(offset.fix())),
+    ];
+    if let Some(name) = offset.abbreviation() {
+        lines.push(format!("TZNAME:{name}"));
+    }
+    lines.push(format!("END:{kind}"));
+    lines
+}
+
+/// "+0100", "-0330"
+fn format_offset(offset: FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!("{sign}{:02}{:02}", minutes / 60, minutes % 60)
+}
+
+/// Negative DURATION before the start, e.g. "-P1D", "-PT1H30M".
+fn format_trigger(minutes_before: u32) -> String {
+    let (days, hours, minutes) = (
+        minutes_before / 1440,
+        minutes_before % 1440 / 60,
+        minutes_before % 60,
+    );
+
+    let mut trigger = "-P".to_string();
+    if days > 0 {
+        trigger.push_str(&format!("{days}D"));
+    }
+    if hours > 0 || minutes > 0 || days == 0 {
+        trigger.push('T');
+        if hours > 0 {
+            trigger.push_str(&format!("{hours}H"));
+        }
+        if minutes > 0 || hours == 0 {
+            trigger.push_str(&format!("{minutes}M"));
+        }
+    }
+    trigger
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11).
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line at 75 octets without splitting a UTF-8 character.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, now, utc, validated};
+
+    fn fixture() -> Fixture {
+        test_support::fixture().on_arsenal_page().build()
+    }
+
+    /// Unfolded content lines.
+    fn lines(ics: &str) -> Vec<String> {
+        ics.replace("\r\n ", "")
+            .split("\r\n")
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_event_fields() {
+        let ics = IcsExporter::new()
+            .with_sequence("arsenal-premier-league-20250816@calpal", 3)
+            .export(&[validated(fixture())], now());
+        let lines = lines(&ics);
+
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        for expected in [
+            "UID:arsenal-premier-league-20250816@calpal",
+            "DTSTAMP:20250801T120000Z",
+            "DTSTART;TZID=Europe/London:20250816T150000",
+            "DTEND;TZID=Europe/London:20250816T165500",
+            "SUMMARY:Arsenal vs Chelsea",
+            "LOCATION:Emirates Stadium",
+            "DESCRIPTION:Arsenal vs Chelsea at Emirates Stadium\\nCompetition: Premier League",
+            "URL:https://www.arsenal.com/fixtures",
+            "STATUS:CONFIRMED",
+            "SEQUENCE:3",
+        ] {
+            assert!(lines.contains(&expected.to_string()), "missing {expected}");
+        }
+    }
+
+    #[test]
+    fn test_vtimezone_transitions() {
+        let lines = lines(&IcsExporter::new().export(&[validated(fixture())], now()));
+        let start = lines.iter().position(|l| l == "BEGIN:VTIMEZONE").unwrap();
+        let end = lines.iter().position(|l| l == "END:VTIMEZONE").unwrap();
+        let vtimezone = &lines[start..=end];
+
+        assert!(vtimezone.contains(&"TZID:Europe/London".to_string()));
+        // Clocks go forward 30 March 2025 01:00 GMT, back 26 October 02:00 BST
+        let daylight = vtimezone
+            .iter()
+            .position(|l| l == "BEGIN:DAYLIGHT")
+            .unwrap();
+        assert_eq!(vtimezone[daylight + 1], "DTSTART:20250330T010000");
+        assert_eq!(vtimezone[daylight + 3], "TZOFFSETTO:+0100");
+        assert_eq!(vtimezone[daylight + 4], "TZNAME:BST");
+        assert!(vtimezone.contains(&"DTSTART:20251026T020000".to_string()));
+        assert_eq!(
+            vtimezone
+                .iter()
+                .filter(|l| l.starts_with("BEGIN:STANDARD"))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_called_off_and_tentative() {
+        let postponed = fixture().with_status(FixtureStatus::Postponed);
+        let mut draw = fixture();
+        draw.opponent = "TBD Opponent".to_string();
+        draw.competition = "Carabao Cup".to_string();
+
+        let lines =
+            lines(&IcsExporter::new().export(&[validated(postponed), validated(draw)], now()));
+        assert!(lines.contains(&"SUMMARY:[POSTPONED] Arsenal vs Chelsea".to_string()));
+        assert!(lines.contains(&"STATUS:CANCELLED".to_string()));
+        assert!(lines.contains(&"STATUS:TENTATIVE".to_string()));
+    }
+
+    #[test]
+    fn test_reminder_alarms() {
+        let reminders = ReminderPolicy::from_json(
+            r#"{ "rules": [ { "team": "Arsenal", "reminders": [
+                    { "minutes_before": 1440 },
+                    { "minutes_before": 90, "description": "Head to the pub for {title}" }
+                ] } ] }"#,
+        )
+        .unwrap();
+        let exporter = IcsExporter::new().with_reminders(reminders);
+
+        let alarms = lines(&exporter.export(&[validated(fixture())], now()));
+        assert_eq!(alarms.iter().filter(|l| *l == "BEGIN:VALARM").count(), 2);
+        assert!(alarms.contains(&"TRIGGER:-P1D".to_string()));
+        assert!(alarms.contains(&"TRIGGER:-PT1H30M".to_string()));
+        assert!(alarms.contains(&"DESCRIPTION:Head to the pub for Arsenal vs Chelsea".to_string()));
+
+        // No reminders for a match that won't happen
+        let postponed = fixture().with_status(FixtureStatus::Postponed);
+        let cancelled = lines(&exporter.export(&[validated(postponed)], now()));
+        assert!(!cancelled.contains(&"BEGIN:VALARM".to_string()));
+
+        assert_eq!(format_trigger(0), "-PT0M");
+        assert_eq!(format_trigger(60), "-PT1H");
+        assert_eq!(format_trigger(1500), "-P1DT1H");
+    }
+
+    #[test]
+    fn test_event_template() {
+        let mut unknown_venue = fixture();
+        unknown_venue.venue = "TBD Venue".to_string();
+        let exporter = IcsExporter::new().with_template(EventTemplate::terse());
+
+        let lines = lines(&exporter.export(&[validated(unknown_venue)], now()));
+        assert!(lines.contains(&"SUMMARY:ARS v CHE (PL)".to_string()));
+        assert!(lines.contains(&"DESCRIPTION:Sat 16 Aug 15:00".to_string()));
+        assert!(!lines.iter().any(|l| l.starts_with("LOCATION:")));
+    }
+
+    #[test]
+    fn test_uid_survives_reschedule_and_draw() {
+        let original = fixture();
+        let mut moved = fixture().with_status(FixtureStatus::Rescheduled {
+            previous: original.datetime,
+        });
+        moved.datetime += Duration::days(1);
+        moved.opponent = "Spurs".to_string();
+
+        assert_eq!(IcsExporter::uid(&original), IcsExporter::uid(&moved));
+    }
+
+    #[test]
+    fn test_same_day_fixtures_get_distinct_uids() {
+        let friendly = |opponent, hour| {
+            test_support::fixture()
+                .opponent(opponent)
+                .competition("Friendly")
+                .at(utc(2025, 8, 9, hour, 0))
+                .validated()
+        };
+        let double_header = vec![friendly("Villarreal", 11), friendly("Athletic Club", 16)];
+
+        let uids = IcsExporter::uids(&double_header);
+        assert_eq!(
+            uids,
+            vec![
+                "arsenal-friendly-20250809-1100@calpal",
+                "arsenal-friendly-20250809-1600@calpal"
+            ]
+        );
+        let calendar = lines(&IcsExporter::new().export(&double_header, now()));
+        for uid in &uids {
+            assert!(calendar.contains(&format!("UID:{uid}")));
+        }
+
+        // A lone fixture keeps the plain UID
+        let single = &double_header[..1];
+        assert_eq!(
+            IcsExporter::uids(single),
+            vec![IcsExporter::uid(&single[0].fixture)]
+        );
+    }
+
+    #[test]
+    fn test_escaping_and_folding() {
+        assert_eq!(escape_text("a, b; c\\d\ne"), "a\\, b\\; c\\\\d\\ne");
+
+        let long = format!("DESCRIPTION:{}", "é".repeat(60));
+        let folded = fold(&long);
+        assert!(folded
+            .split("\r\n")
+            .all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert_eq!(folded.replace("\r\n ", ""), long);
+    }
+}
@@ -11,7 +11,7 @@
 //!
 //! ## Key Design Principles
 //!
-//! - **Time Independence**: All parsing uses injected time for deterministic tests
+//! - **Time Independence**: Parsing, validation and scrapers read an injected `Clock`
 //! - **Rich Metadata**: Structured `ParseMetadata` instead of primitive strings
 //! - **Graceful Degradation**: Multiple parsing strategies with fallbacks
 //! - **Calendar First**: Validation designed for organizing friend watching parties
@@ -34,7 +34,7 @@
 //! # });
 //! ```
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -42,11 +42,34 @@ pub mod arsenal;
 pub mod arsenal_integration_tests;
 pub mod browser;
 pub mod browser_tests;
+pub mod changes;
+pub mod clock;
+pub mod confidence;
+pub mod csv;
 pub mod debug_browser;
+pub mod dedupe;
+pub mod duration;
+pub mod ics;
+pub mod locale;
 pub mod parsing;
+pub mod planner;
+pub mod policy;
+pub mod reminders;
+pub mod rules;
+pub mod schema;
+pub mod season;
+pub mod sport;
+pub mod status;
+pub mod store;
+pub mod templates;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod validation;
 
+use duration::{DurationDefaults, ExpectedDuration};
 use parsing::ParseMetadata;
+use sport::{Sport, SportDetails};
+use status::FixtureStatus;
 
 /// Core fixture representation with rich parsing metadata.
 ///
@@ -70,6 +93,7 @@ use parsing::ParseMetadata;
 ///     weekday_mismatch: None,
 ///     timezone_assumptions: "Parsed as Europe/London timezone".to_string(),
 ///     parsing_strategy: ParsingStrategy::ExactMatch,
+///     ..Default::default()
 /// };
 ///
 /// let fixture = Fixture::new(
@@ -95,6 +119,25 @@ pub struct Fixture {
     pub competition: String,
     /// Rich parsing metadata for quality assessment and debugging
     pub parse_metadata: ParseMetadata,
+    /// Squad within the club (men's first team, women's team, academy)
+    #[serde(default)]
+    pub squad: Squad,
+    /// Sport being played (football unless a scraper says otherwise)
+    #[serde(default)]
+    pub sport: Sport,
+    /// Sport-specific extensions (rugby match type, cricket format, motorsport session)
+    #[serde(default)]
+    pub sport_details: Option<SportDetails>,
+    /// Expected length, set by the scraper from its configured defaults; the
+    /// standard defaults apply when unset
+    #[serde(default)]
+    pub expected_duration: Option<ExpectedDuration>,
+    /// Where the fixture was read; several entries once duplicates are merged
+    #[serde(default)]
+    pub sources: Vec<FixtureSource>,
+    /// Lifecycle status (postponed, cancelled, ...); scheduled unless a source says otherwise
+    #[serde(default)]
+    pub status: FixtureStatus,
 }
 
 impl Fixture {
@@ -113,9 +156,111 @@ impl Fixture {
             venue,
             competition,
             parse_metadata,
+            squad: Squad::default(),
+            sport: Sport::default(),
+            sport_details: None,
+            expected_duration: None,
+            sources: Vec::new(),
+            status: FixtureStatus::default(),
+        }
+    }
+
+    /// Tag the fixture with the squad it belongs to (defaults to the men's first team).
+    pub fn with_squad(mut self, squad: Squad) -> Self {
+        self.squad = squad;
+        self
+    }
+
+    /// Set the sport for fixtures without sport-specific details.
+    pub fn with_sport(mut self, sport: Sport) -> Self {
+        self.sport = sport;
+        self
+    }
+
+    /// Attach sport-specific details; the fixture's sport follows the details.
+    pub fn with_sport_details(mut self, details: SportDetails) -> Self {
+        self.sport = details.sport();
+        self.sport_details = Some(details);
+        self
+    }
+
+    /// Override the expected duration (for sources that publish it).
+    pub fn with_expected_duration(mut self, duration: ExpectedDuration) -> Self {
+        self.expected_duration = Some(duration);
+        self
+    }
+
+    /// Set the lifecycle status (e.g. from "P-P" markup).
+    pub fn with_status(mut self, status: FixtureStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Record where the fixture was read.
+    pub fn with_source(mut self, source: FixtureSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Expected duration: the one the scraper set, else the standard sport and
+    /// competition defaults.
+    pub fn duration(&self) -> ExpectedDuration {
+        self.expected_duration
+            .unwrap_or_else(|| DurationDefaults::standard().for_fixture(self))
+    }
+
+    /// Expected end of regular play (used as the calendar event end).
+    pub fn expected_end(&self) -> DateTime<Utc> {
+        self.datetime + Duration::minutes(i64::from(self.duration().minutes))
+    }
+
+    /// Full time window the event may occupy, including possible extra time.
+    ///
+    /// Clash detection uses this window so a cup tie that runs long still
+    /// counts as overlapping whatever was scheduled straight after it.
+    pub fn time_window(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let total = i64::from(self.duration().total_minutes());
+        (self.datetime, self.datetime + Duration::minutes(total))
+    }
+
+    /// Short event title for listings and calendars.
+    ///
+    /// Head-to-head sports read "Arsenal vs Chelsea"; motorsport has no single
+    /// opponent, so the event name and session are used instead.
+    pub fn title(&self) -> String {
+        if self.sport.is_head_to_head() {
+            format!("{} vs {}", self.team_label(), self.opponent)
+        } else {
+            match &self.sport_details {
+                Some(SportDetails::Motorsport { session, .. }) => {
+                    format!("{} - {} ({session})", self.team_label(), self.opponent)
+                }
+                _ => format!("{} - {}", self.team_label(), self.opponent),
+            }
         }
     }
 
+    /// Team name including the squad, e.g. "Arsenal Women".
+    ///
+    /// This is the name a friend group subscribes to, so each squad
+    /// reads as its own team in listings and calendars.
+    pub fn team_label(&self) -> String {
+        match self.squad.team_suffix() {
+            Some(suffix) => format!("{} {suffix}", self.team),
+            None => self.team.clone(),
+        }
+    }
+
+    /// Whether the competition name contains `keyword`, ignoring case.
+    ///
+    /// Every competition filter in config ("cup", "Premier League") matches
+    /// this way, so one keyword covers the FA Cup and the Carabao Cup alike.
+    pub fn competition_matches(&self, keyword: &str) -> bool {
+        self.competition
+            .to_lowercase()
+            .contains(&keyword.to_lowercase())
+    }
+
     /// Convert fixture time to London timezone (GMT/BST) for display.
     ///
     /// This is the primary display method since Ollie is in London and
@@ -126,6 +271,80 @@ impl Fixture {
     }
 }
 
+/// Squad (team variant) within a club.
+///
+/// Clubs like Arsenal publish separate fixture lists for their men's, women's
+/// and academy sides with near-identical markup. The squad keeps those
+/// fixtures apart so each can be followed and filtered independently.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum Squad {
+    /// Men's first team (the default for every scraper)
+    #[default]
+    Men,
+    /// Women's first team
+    Women,
+    /// Academy / youth sides
+    Academy,
+}
+
+impl Squad {
+    /// Suffix appended to the club name for display ("Arsenal Women").
+    ///
+    /// The men's first team is conventionally shown with the bare club name.
+    pub fn team_suffix(&self) -> Option<&'static str> {
+        match self {
+            Squad::Men => None,
+            Squad::Women => Some("Women"),
+            Squad::Academy => Some("Academy"),
+        }
+    }
+}
+
+impl fmt::Display for Squad {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Squad::Men => write!(f, "Men"),
+            Squad::Women => write!(f, "Women"),
+            Squad::Academy => write!(f, "Academy"),
+        }
+    }
+}
+
+/// A page (and optionally a section of it) a fixture was read from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FixtureSource {
+    /// Scraper or feed name, e.g. "Arsenal"
+    pub name: String,
+    pub url: String,
+    /// Part of the page, e.g. "accordion" or "teaser"
+    #[serde(default)]
+    pub section: Option<String>,
+}
+
+impl FixtureSource {
+    pub fn new(name: &str, url: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            url: url.to_string(),
+            section: None,
+        }
+    }
+
+    pub fn with_section(mut self, section: &str) -> Self {
+        self.section = Some(section.to_string());
+        self
+    }
+}
+
+impl fmt::Display for FixtureSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.section {
+            Some(section) => write!(f, "{} ({}, {section})", self.name, self.url),
+            None => write!(f, "{} ({})", self.name, self.url),
+        }
+    }
+}
+
 /// Comprehensive error types for the scraping pipeline.
 ///
 /// Uses anyhow for error propagation but provides structured error types
@@ -140,6 +359,8 @@ pub enum ScrapeError {
     InvalidDateTime(String),
     /// Missing HTML elements (selectors not found, empty pages)
     MissingElement(String),
+    /// Fixture store failures (database errors, unreadable store files)
+    Storage(String),
 }
 
 impl fmt::Display for ScrapeError {
@@ -149,6 +370,7 @@ impl fmt::Display for ScrapeError {
             ScrapeError::Parse(msg) => write!(f, "Parse error: {msg}"),
             ScrapeError::InvalidDateTime(msg) => write!(f, "Invalid datetime: {msg}"),
             ScrapeError::MissingElement(msg) => write!(f, "Missing element: {msg}"),
+            ScrapeError::Storage(msg) => write!(f, "Storage error: {msg}"),
         }
     }
 }
@@ -215,6 +437,15 @@ pub trait FixtureScraper {
     fn source_url(&self) -> &str;
 }
 
+/// Writes validated fixtures in a format for calendars or spreadsheets.
+///
+/// Implemented by [`ics::IcsExporter`] and [`csv::CsvExporter`]; saved JSON
+/// files use [`schema::FixtureFile`] instead.
+pub trait Exporter {
+    /// The whole file. `now` stamps formats that record when they were generated.
+    fn export(&self, fixtures: &[validation::ValidatedFixture], now: DateTime<Utc>) -> String;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,7 +458,9 @@ mod tests {
     impl FixtureScraper for MockScraper {
         async fn scrape(&self) -> Result<Vec<validation::ValidatedFixture>, ScrapeError> {
             let fixture = create_test_fixture();
-            let validated = validation::ValidatedFixture::new(fixture);
+            // Scrape "before" the fixture so the result doesn't depend on the real date
+            let clock = clock::FixedClock::new(Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap());
+            let validated = validation::ValidatedFixture::with_clock(fixture, &clock);
             Ok(vec![validated])
         }
 
@@ -246,6 +479,7 @@ mod tests {
             weekday_mismatch: None,
             timezone_assumptions: "Parsed as UTC timezone".to_string(),
             parsing_strategy: parsing::ParsingStrategy::ExactMatch,
+            ..Default::default()
         };
 
         Fixture::new(
@@ -267,6 +501,73 @@ mod tests {
         assert_eq!(fixture.venue, "Emirates Stadium");
         assert_eq!(fixture.competition, "Premier League");
         assert_eq!(fixture.parse_metadata.original_source, "Fri Aug 15 16:30");
+        assert_eq!(fixture.squad, Squad::Men);
+    }
+
+    #[test]
+    fn test_squad_team_label() {
+        let fixture = create_test_fixture();
+        assert_eq!(fixture.team_label(), "Arsenal");
+
+        let women_fixture = create_test_fixture().with_squad(Squad::Women);
+        assert_eq!(women_fixture.team_label(), "Arsenal Women");
+        assert_eq!(women_fixture.team, "Arsenal");
+    }
+
+    #[test]
+    fn test_squad_defaults_when_missing_from_json() {
+        // Older JSON output predates the squad field and must still load
+        let fixture = create_test_fixture();
+        let mut json = serde_json::to_value(&fixture).expect("Should serialize to JSON");
+        json.as_object_mut().unwrap().remove("squad");
+
+        let deserialized: Fixture =
+            serde_json::from_value(json).expect("Should deserialize without squad");
+        assert_eq!(deserialized.squad, Squad::Men);
+        assert_eq!(deserialized.sport, Sport::Football);
+        assert!(deserialized.sport_details.is_none());
+    }
+
+    #[test]
+    fn test_fixture_time_window() {
+        let fixture = create_test_fixture();
+        let (start, end) = fixture.time_window();
+        assert_eq!(start, fixture.datetime);
+        assert_eq!(end, fixture.expected_end()); // League match, no extra time
+
+        let cup_tie = create_test_fixture()
+            .with_expected_duration(ExpectedDuration::regular(115).with_extra_time(50));
+        let (_, cup_end) = cup_tie.time_window();
+        assert_eq!(cup_end - cup_tie.datetime, Duration::minutes(165));
+        assert_eq!(
+            cup_tie.expected_end() - cup_tie.datetime,
+            Duration::minutes(115)
+        );
+    }
+
+    #[test]
+    fn test_fixture_title_by_sport() {
+        use sport::{MotorsportSession, RugbyCode, RugbyMatchType};
+
+        let football = create_test_fixture();
+        assert_eq!(football.title(), "Arsenal vs Chelsea");
+
+        let mut rugby = create_test_fixture().with_sport_details(SportDetails::Rugby {
+            code: RugbyCode::Union,
+            match_type: RugbyMatchType::Test,
+        });
+        rugby.team = "Springboks".to_string();
+        rugby.opponent = "All Blacks".to_string();
+        assert_eq!(rugby.sport, Sport::RugbyUnion);
+        assert_eq!(rugby.title(), "Springboks vs All Blacks");
+
+        let mut f1 = create_test_fixture().with_sport_details(SportDetails::Motorsport {
+            series: "Formula 1".to_string(),
+            session: MotorsportSession::Race,
+        });
+        f1.team = "McLaren".to_string();
+        f1.opponent = "British Grand Prix".to_string();
+        assert_eq!(f1.title(), "McLaren - British Grand Prix (Race)");
     }
 
     #[test]
@@ -344,6 +645,7 @@ mod tests {
             weekday_mismatch: None,
             timezone_assumptions: "Parsed as UTC timezone".to_string(),
             parsing_strategy: parsing::ParsingStrategy::ExactMatch,
+            ..Default::default()
         };
 
         let winter_fixture = Fixture::new(
@@ -0,0 +1,345 @@
+//! # Locale Packs for Month and Weekday Names
+//!
+//! chrono only understands English month and weekday names, but fixture sources
+//! don't: Springboks coverage may be in Afrikaans, and club and UEFA pages come in
+//! French, Spanish, German and Italian. A [`Locale`] knows the full and abbreviated
+//! names for its language and translates source text into the English tokens the
+//! multi-stage parser expects ("samedi 16 août" → "Sat 16 Aug").
+//!
+//! ## Ambiguity
+//!
+//! Some abbreviations collide within a language (Spanish and Italian "mar" is both
+//! Tuesday and March). The first token of a date is read as a weekday when it can
+//! be one; every later token is read as a month first.
+
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+const ENGLISH_SHORT_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Language used for month and weekday names in a source.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    English,
+    Afrikaans,
+    French,
+    Spanish,
+    German,
+    Italian,
+}
+
+impl Locale {
+    /// Every supported locale, English first.
+    pub const ALL: [Locale; 6] = [
+        Locale::English,
+        Locale::Afrikaans,
+        Locale::French,
+        Locale::Spanish,
+        Locale::German,
+        Locale::Italian,
+    ];
+
+    /// Month names (full and abbreviated, lowercase), January first.
+    fn month_names(&self) -> [&'static [&'static str]; 12] {
+        match self {
+            Locale::English => [
+                &["january", "jan"],
+                &["february", "feb"],
+                &["march", "mar"],
+                &["april", "apr"],
+                &["may"],
+                &["june", "jun"],
+                &["july", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["october", "oct"],
+                &["november", "nov"],
+                &["december", "dec"],
+            ],
+            Locale::Afrikaans => [
+                &["januarie", "jan"],
+                &["februarie", "feb"],
+                &["maart", "mrt"],
+                &["april", "apr"],
+                &["mei"],
+                &["junie", "jun"],
+                &["julie", "jul"],
+                &["augustus", "aug"],
+                &["september", "sep", "sept"],
+                &["oktober", "okt"],
+                &["november", "nov"],
+                &["desember", "des"],
+            ],
+            Locale::French => [
+                &["janvier", "janv"],
+                &["février", "fevrier", "févr", "fevr", "fév", "fev"],
+                &["mars"],
+                &["avril", "avr"],
+                &["mai"],
+                &["juin"],
+                &["juillet", "juil"],
+                &["août", "aout"],
+                &["septembre", "sept"],
+                &["octobre", "oct"],
+                &["novembre", "nov"],
+                &["décembre", "decembre", "déc", "dec"],
+            ],
+            Locale::Spanish => [
+                &["enero", "ene"],
+                &["febrero", "feb"],
+                &["marzo", "mar"],
+                &["abril", "abr"],
+                &["mayo", "may"],
+                &["junio", "jun"],
+                &["julio", "jul"],
+                &["agosto", "ago"],
+                &["septiembre", "setiembre", "sep", "sept", "set"],
+                &["octubre", "oct"],
+                &["noviembre", "nov"],
+                &["diciembre", "dic"],
+            ],
+            Locale::German => [
+                &["januar", "jänner", "jan", "jän"],
+                &["februar", "feb"],
+                &["märz", "maerz", "mär", "mrz"],
+                &["april", "apr"],
+                &["mai"],
+                &["juni", "jun"],
+                &["juli", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["oktober", "okt"],
+                &["november", "nov"],
+                &["dezember", "dez"],
+            ],
+            Locale::Italian => [
+                &["gennaio", "gen"],
+                &["febbraio", "feb"],
+                &["marzo", "mar"],
+                &["aprile", "apr"],
+                &["maggio", "mag"],
+                &["giugno", "giu"],
+                &["luglio", "lug"],
+                &["agosto", "ago"],
+                &["settembre", "set"],
+                &["ottobre", "ott"],
+                &["novembre", "nov"],
+                &["dicembre", "dic"],
+            ],
+        }
+    }
+
+    /// Weekday names (full and abbreviated, lowercase), Monday first.
+    fn weekday_names(&self) -> [&'static [&'static str]; 7] {
+        match self {
+            Locale::English => [
+                &["monday", "mon"],
+                &["tuesday", "tue", "tues"],
+                &["wednesday", "wed", "weds"],
+                &["thursday", "thu", "thur", "thurs"],
+                &["friday", "fri"],
+                &["saturday", "sat"],
+                &["sunday", "sun"],
+            ],
+            Locale::Afrikaans => [
+                &["maandag", "ma"],
+                &["dinsdag", "di"],
+                &["woensdag", "wo"],
+                &["donderdag", "do"],
+                &["vrydag", "vr"],
+                &["saterdag", "sa"],
+                &["sondag", "so"],
+            ],
+            Locale::French => [
+                &["lundi", "lun"],
+                &["mardi", "mar"],
+                &["mercredi", "mer"],
+                &["jeudi", "jeu"],
+                &["vendredi", "ven"],
+                &["samedi", "sam"],
+                &["dimanche", "dim"],
+            ],
+            Locale::Spanish => [
+                &["lunes", "lun"],
+                &["martes", "mar"],
+                &["miércoles", "miercoles", "mié", "mie"],
+                &["jueves", "jue"],
+                &["viernes", "vie"],
+                &["sábado", "sabado", "sáb", "sab"],
+                &["domingo", "dom"],
+            ],
+            Locale::German => [
+                &["montag", "mo"],
+                &["dienstag", "di"],
+                &["mittwoch", "mi"],
+                &["donnerstag", "do"],
+                &["freitag", "fr"],
+                &["samstag", "sonnabend", "sa"],
+                &["sonntag", "so"],
+            ],
+            Locale::Italian => [
+                &["lunedì", "lunedi", "lun"],
+                &["martedì", "martedi", "mar"],
+                &["mercoledì", "mercoledi", "mer"],
+                &["giovedì", "giovedi", "gio"],
+                &["venerdì", "venerdi", "ven"],
+                &["sabato", "sab"],
+                &["domenica", "dom"],
+            ],
+        }
+    }
+
+    /// Connecting words that carry no date information ("15 de agosto").
+    fn filler_words(&self) -> &'static [&'static str] {
+        match self {
+            Locale::Spanish => &["de", "del"],
+            Locale::French => &["le"],
+            Locale::German => &["den"],
+            Locale::English | Locale::Afrikaans | Locale::Italian => &[],
+        }
+    }
+
+    /// Month number (1-12) for a full or abbreviated month name.
+    pub fn month_from_name(&self, name: &str) -> Option<u32> {
+        let name = clean_token(name);
+        self.month_names()
+            .iter()
+            .position(|names| names.contains(&name.as_str()))
+            .map(|index| index as u32 + 1)
+    }
+
+    /// Weekday for a full or abbreviated weekday name.
+    pub fn weekday_from_name(&self, name: &str) -> Option<Weekday> {
+        let name = clean_token(name);
+        self.weekday_names()
+            .iter()
+            .position(|names| names.contains(&name.as_str()))
+            .map(|index| WEEKDAYS[index])
+    }
+
+    /// Rewrite a date string into English tokens the parser's chrono patterns understand.
+    ///
+    /// Unknown tokens (numbers, already-English words) pass through unchanged, and
+    /// a trailing dot on a day number ("15.") is dropped.
+    pub fn translate_date(&self, date: &str) -> String {
+        date.split_whitespace()
+            .enumerate()
+            .filter(|(_, token)| !self.filler_words().contains(&clean_token(token).as_str()))
+            .map(|(position, token)| self.translate_token(token, position == 0))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn translate_token(&self, token: &str, may_be_weekday: bool) -> String {
+        let as_weekday = || {
+            self.weekday_from_name(token)
+                .map(|weekday| weekday.to_string())
+        };
+        let as_month = || {
+            self.month_from_name(token)
+                .map(|month| ENGLISH_SHORT_MONTHS[month as usize - 1].to_string())
+        };
+
+        let translated = if may_be_weekday {
+            as_weekday().or_else(as_month)
+        } else {
+            as_month().or_else(as_weekday)
+        };
+
+        translated.unwrap_or_else(|| {
+            let trimmed = token.trim_end_matches('.');
+            if !trimmed.is_empty() && trimmed.chars().all(|c| c.is_ascii_digit()) {
+                trimmed.to_string()
+            } else {
+                token.to_string()
+            }
+        })
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Locale::English => write!(f, "English"),
+            Locale::Afrikaans => write!(f, "Afrikaans"),
+            Locale::French => write!(f, "French"),
+            Locale::Spanish => write!(f, "Spanish"),
+            Locale::German => write!(f, "German"),
+            Locale::Italian => write!(f, "Italian"),
+        }
+    }
+}
+
+fn clean_token(token: &str) -> String {
+    token.trim_end_matches(['.', ',']).to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_lookup_full_and_abbreviated() {
+        assert_eq!(Locale::Afrikaans.month_from_name("Augustus"), Some(8));
+        assert_eq!(Locale::Afrikaans.month_from_name("Mrt"), Some(3));
+        assert_eq!(Locale::French.month_from_name("août"), Some(8));
+        assert_eq!(Locale::French.month_from_name("janv."), Some(1));
+        assert_eq!(Locale::German.month_from_name("März"), Some(3));
+        assert_eq!(Locale::Italian.month_from_name("Dicembre"), Some(12));
+        assert_eq!(Locale::English.month_from_name("Augustus"), None);
+    }
+
+    #[test]
+    fn test_weekday_lookup() {
+        assert_eq!(
+            Locale::Afrikaans.weekday_from_name("Saterdag"),
+            Some(Weekday::Sat)
+        );
+        assert_eq!(Locale::German.weekday_from_name("So."), Some(Weekday::Sun));
+        assert_eq!(
+            Locale::Spanish.weekday_from_name("miércoles"),
+            Some(Weekday::Wed)
+        );
+        assert_eq!(
+            Locale::English.weekday_from_name("Thurs"),
+            Some(Weekday::Thu)
+        );
+    }
+
+    #[test]
+    fn test_translate_date() {
+        assert_eq!(
+            Locale::French.translate_date("samedi 16 août"),
+            "Sat 16 Aug"
+        );
+        assert_eq!(
+            Locale::Spanish.translate_date("sábado 16 de agosto"),
+            "Sat 16 Aug"
+        );
+        assert_eq!(Locale::German.translate_date("Sa. 16. Aug."), "Sat 16 Aug");
+        assert_eq!(
+            Locale::Afrikaans.translate_date("Saterdag 16 Augustus"),
+            "Sat 16 Aug"
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_abbreviation_uses_position() {
+        // Spanish "mar" is Tuesday first, March anywhere else
+        assert_eq!(Locale::Spanish.translate_date("mar 18 mar"), "Tue 18 Mar");
+    }
+}
@@ -10,26 +10,107 @@
 //!
 //! 1. **Exact Match**: Try perfect parsing first
 //! 2. **Weekday Tolerance**: Ignore incorrect weekdays, record the mismatch
-//! 3. **Year Assumptions**: Try adjacent years for edge cases
+//! 3. **Year Assumptions**: Try adjacent years for edge cases, or resolve the
+//!    year from a [`Season`] context when the scraper provides one
 //! 4. **Rich Metadata**: Track what decisions were made for validation
 //!
+//! Listings close to kick-off often drop the date altogether ("Today 20:00",
+//! "Tomorrow", "This Saturday"). These relative expressions are resolved against
+//! the parser's current time in its default timezone before the stages above run.
+//!
+//! ## Format Families
+//!
+//! Every stage walks an ordered list of [`DateFormatFamily`] values. Month-first
+//! ("Sat Aug 15") and day-first ("Sat 15 Aug") text, ordinal suffixes ("15th August"),
+//! the 12-hour clock ("3pm"), numeric dates ("15/08/2025") and ISO strings are all
+//! supported. Scrapers reorder or trim the list to express their source's preference
+//! (e.g. a US site putting `NumericMonthFirst` ahead of `NumericDayFirst`), and the
+//! format that matched is recorded in `ParseMetadata::date_format`.
+//!
+//! ## Locales
+//!
+//! Month and weekday names are translated through [`Locale`] packs before the
+//! chrono patterns run, so "samedi 16 août" and "Saterdag 16 Augustus" go through
+//! the same stages as "Sat 16 Aug". Locales are tried in order and the one that
+//! parsed is recorded in `ParseMetadata::locale`.
+//!
+//! ## Clock Changes
+//!
+//! Kick-offs around the late-March and late-October clock changes may name a
+//! local time that is skipped or happens twice. A [`DstPolicy`] decides which
+//! instant is meant (or hands the time to the fallback timezone), and the choice
+//! is recorded in `ParseMetadata::dst_resolution` so validation can flag it.
+//!
 //! ## Time Independence
 //!
-//! All parsing can inject a "current time" for deterministic testing.
+//! "Now" comes from an injected [`Clock`] (the system clock by default), so
+//! tests and simulations pin it with a `FixedClock`.
 //! Tests work in 2027 because they don't depend on `Utc::now()`.
 
+use crate::clock::{Clock, FixedClock, SystemClock};
+use crate::confidence::FixtureField;
+use crate::locale::Locale;
+use crate::season::Season;
 use crate::ScrapeError;
-use chrono::{DateTime, Datelike, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono::{
+    DateTime, Datelike, Days, Duration, LocalResult, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Utc, Weekday,
+};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::Arc;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct ParseMetadata {
     pub original_source: String,
     pub weekday_mismatch: Option<WeekdayMismatch>,
     pub timezone_assumptions: String,
     pub parsing_strategy: ParsingStrategy,
+    /// Date format that matched the source text (`None` for hand-built metadata)
+    #[serde(default)]
+    pub date_format: Option<MatchedFormat>,
+    /// Locale whose month and weekday names were recognised
+    #[serde(default)]
+    pub locale: Option<Locale>,
+    /// How a local time skipped or repeated by a clock change was resolved
+    #[serde(default)]
+    pub dst_resolution: Option<DstResolution>,
+    /// Fields the scraper filled with a placeholder ("TBD Venue")
+    #[serde(default)]
+    pub field_fallbacks: Vec<FixtureField>,
+    /// Every kick-off the source states, for cross-checking (empty when the scraper doesn't)
+    #[serde(default)]
+    pub kick_off_readings: Vec<KickOffReading>,
+}
+
+/// Where on a fixture page a kick-off time was read from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum KickOffSource {
+    /// Machine-readable `datetime` attribute
+    DatetimeAttribute,
+    /// Text shown to visitors ("Sat 15 Aug 15:00")
+    VisibleText,
+    /// Screen-reader header ("Newcastle United - Sat Aug 15 - 15:00")
+    HiddenHeader,
+}
+
+impl fmt::Display for KickOffSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KickOffSource::DatetimeAttribute => write!(f, "datetime attribute"),
+            KickOffSource::VisibleText => write!(f, "visible text"),
+            KickOffSource::HiddenHeader => write!(f, "hidden header"),
+        }
+    }
+}
+
+/// One statement of the kick-off time and what it parsed to (`None` if it didn't).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KickOffReading {
+    pub source: KickOffSource,
+    pub text: String,
+    pub parsed: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -39,18 +120,209 @@ pub struct WeekdayMismatch {
     pub date: String,
 }
 
+/// How to read a local time that a clock change skips or repeats.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum DstPolicy {
+    /// RFC 5545 behaviour: first occurrence of a repeated time, and a skipped
+    /// time read with the offset before the change (01:30 becomes 02:30 BST)
+    #[default]
+    ShiftForward,
+    /// The earlier of the two candidate instants
+    Earliest,
+    /// The later of the two candidate instants
+    Latest,
+    /// Don't guess in the default timezone; parse in the fallback timezone instead
+    Fallback,
+}
+
+/// Whether a local time fell into a clock change's gap or overlap.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DstTransition {
+    /// Clocks went forward over this time, so it never happened
+    Skipped,
+    /// Clocks went back over this time, so it happened twice
+    Ambiguous,
+}
+
+/// Record of a clock-change adjustment made while parsing.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DstResolution {
+    pub transition: DstTransition,
+    pub policy: DstPolicy,
+    /// Local time as written in the source
+    pub local_time: NaiveDateTime,
+    pub timezone: String,
+    /// UTC offset that was chosen, e.g. "+01:00"
+    pub resolved_offset: String,
+}
+
+impl DstResolution {
+    /// Human-readable explanation for validation messages.
+    pub fn describe(&self) -> String {
+        let what = match self.transition {
+            DstTransition::Skipped => "does not exist (clocks go forward)",
+            DstTransition::Ambiguous => "occurs twice (clocks go back)",
+        };
+        format!(
+            "Local time {} {what} in {}; read as UTC{} ({:?} policy)",
+            self.local_time.format("%Y-%m-%d %H:%M"),
+            self.timezone,
+            self.resolved_offset,
+            self.policy
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub enum ParsingStrategy {
-    ExactMatch,               // Weekday and date matched perfectly
+    #[default]
+    ExactMatch, // Weekday and date matched perfectly
     WeekdayTolerant,          // Ignored incorrect weekday, used date
     YearAssumption(i32),      // Assumed current year
     TimezoneFallback(String), // Used fallback timezone
+    RelativeDate(String),     // Resolved "Today"/"Tomorrow"/"This Saturday" against current time
+    SeasonInference(i32),     // Year taken from the season context
+}
+
+/// A family of date formats found on fixture websites.
+///
+/// `OrdinalSuffix` and `TwelveHourClock` are normalisations rather than layouts:
+/// when enabled, "15th" becomes "15" and "3pm" becomes "15:00" before the
+/// textual and numeric families are tried.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum DateFormatFamily {
+    /// "Sat Aug 15", "Saturday August 15 2025"
+    MonthFirst,
+    /// "Sat 15 Aug", "15 August 2025"
+    DayFirst,
+    /// "15th August", "Aug 15th"
+    OrdinalSuffix,
+    /// "3pm", "7:45 PM"
+    TwelveHourClock,
+    /// "15/08/2025", "15.08.25", "15/08"
+    NumericDayFirst,
+    /// "08/15/2025" (US sites)
+    NumericMonthFirst,
+    /// "2025-08-15T15:00", "2025-08-15T14:00:00.000Z"
+    Iso,
+}
+
+impl DateFormatFamily {
+    /// Default preference order; numeric month-first is opt-in to avoid
+    /// misreading UK "05/08" as May 8th.
+    pub const DEFAULT_ORDER: [DateFormatFamily; 6] = [
+        DateFormatFamily::MonthFirst,
+        DateFormatFamily::DayFirst,
+        DateFormatFamily::OrdinalSuffix,
+        DateFormatFamily::TwelveHourClock,
+        DateFormatFamily::Iso,
+        DateFormatFamily::NumericDayFirst,
+    ];
+
+    /// chrono patterns for this family: `(with weekday, without weekday)`.
+    ///
+    /// Year-less patterns expect the year appended after the date, which is
+    /// how stage 1-3 inject the candidate year. `%A`/`%B` accept both full and
+    /// abbreviated names when parsing.
+    fn patterns(&self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            DateFormatFamily::MonthFirst => (&["%A %B %d %Y %H:%M"], &["%B %d %Y %H:%M"]),
+            DateFormatFamily::DayFirst => (&["%A %d %B %Y %H:%M"], &["%d %B %Y %H:%M"]),
+            DateFormatFamily::NumericDayFirst => (
+                &["%A %d/%m/%Y %H:%M"],
+                &[
+                    "%d/%m/%Y %H:%M",
+                    "%d/%m/%y %H:%M",
+                    "%d.%m.%Y %H:%M",
+                    "%d.%m.%y %H:%M",
+                    "%d-%m-%Y %H:%M",
+                    "%d/%m %Y %H:%M",
+                ],
+            ),
+            DateFormatFamily::NumericMonthFirst => (
+                &["%A %m/%d/%Y %H:%M"],
+                &["%m/%d/%Y %H:%M", "%m/%d/%y %H:%M", "%m/%d %Y %H:%M"],
+            ),
+            DateFormatFamily::Iso => (
+                &[],
+                &[
+                    "%Y-%m-%dT%H:%M:%S%.f",
+                    "%Y-%m-%dT%H:%M:%S",
+                    "%Y-%m-%dT%H:%M",
+                    "%Y-%m-%d %H:%M",
+                ],
+            ),
+            // Normalisations only - they enable preprocessing, not layouts
+            DateFormatFamily::OrdinalSuffix | DateFormatFamily::TwelveHourClock => (&[], &[]),
+        }
+    }
+}
+
+impl fmt::Display for DateFormatFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateFormatFamily::MonthFirst => write!(f, "Month-first"),
+            DateFormatFamily::DayFirst => write!(f, "Day-first"),
+            DateFormatFamily::OrdinalSuffix => write!(f, "Ordinal suffix"),
+            DateFormatFamily::TwelveHourClock => write!(f, "12-hour clock"),
+            DateFormatFamily::NumericDayFirst => write!(f, "Numeric day-first"),
+            DateFormatFamily::NumericMonthFirst => write!(f, "Numeric month-first"),
+            DateFormatFamily::Iso => write!(f, "ISO 8601"),
+        }
+    }
+}
+
+/// The format that successfully parsed a source string.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MatchedFormat {
+    /// Layout family of the date part
+    pub family: DateFormatFamily,
+    /// chrono pattern applied after normalisation
+    pub pattern: String,
+    /// An ordinal suffix ("15th") was stripped
+    pub ordinal_suffix: bool,
+    /// The time was written on a 12-hour clock ("3pm")
+    pub twelve_hour_clock: bool,
+}
+
+impl MatchedFormat {
+    /// ISO 8601 / RFC 3339 timestamp with an explicit offset.
+    pub fn rfc3339() -> Self {
+        Self {
+            family: DateFormatFamily::Iso,
+            pattern: "RFC 3339".to_string(),
+            ordinal_suffix: false,
+            twelve_hour_clock: false,
+        }
+    }
+}
+
+/// Source text after normalisation (commas, ordinals, locale names, 12-hour times).
+struct NormalisedInput {
+    /// Date translated to English tokens
+    date: String,
+    time: String,
+    /// First token as written in the source, kept for weekday-mismatch reporting
+    first_token: Option<String>,
+    ordinal_suffix: bool,
+    twelve_hour_clock: bool,
+}
+
+/// A naive time placed in a timezone, with any clock-change decision made on the way.
+struct Localised {
+    local: DateTime<Tz>,
+    dst_resolution: Option<DstResolution>,
+    used_fallback: bool,
 }
 
 pub struct DateTimeParser {
     default_timezone: Tz,
     fallback_timezone: Tz,
-    current_time: Option<DateTime<Utc>>, // For testing - None = use real time
+    dst_policy: DstPolicy,
+    format_families: Vec<DateFormatFamily>,
+    locales: Vec<Locale>,
+    season: Option<Season>,
+    clock: Arc<dyn Clock>,
 }
 
 impl DateTimeParser {
@@ -58,7 +330,11 @@ impl DateTimeParser {
         Self {
             default_timezone: default_tz,
             fallback_timezone: chrono_tz::UTC,
-            current_time: None, // Production: use real time
+            dst_policy: DstPolicy::default(),
+            format_families: DateFormatFamily::DEFAULT_ORDER.to_vec(),
+            locales: vec![Locale::English],
+            season: None,
+            clock: Arc::new(SystemClock), // Production: use real time
         }
     }
 
@@ -67,16 +343,55 @@ impl DateTimeParser {
         self
     }
 
-    /// For testing: create parser with mocked current time
-    #[cfg(test)]
-    pub fn with_current_time(mut self, current_time: DateTime<Utc>) -> Self {
-        self.current_time = Some(current_time);
+    /// Set how local times skipped or repeated by a clock change are read.
+    pub fn with_dst_policy(mut self, policy: DstPolicy) -> Self {
+        self.dst_policy = policy;
+        self
+    }
+
+    /// Set the format families to try, in order of preference.
+    ///
+    /// Families left out are never tried, so a scraper for a US site can use
+    /// `[NumericMonthFirst, TwelveHourClock]` without risking day-first readings.
+    pub fn with_format_families(mut self, families: Vec<DateFormatFamily>) -> Self {
+        self.format_families = families;
+        self
+    }
+
+    /// Set the locales whose month and weekday names the source may use, in order.
+    ///
+    /// A Springboks source might use `[Afrikaans, English]`; the first locale
+    /// that parses wins and is recorded in the metadata.
+    pub fn with_locales(mut self, locales: Vec<Locale>) -> Self {
+        self.locales = locales;
+        self
+    }
+
+    /// Resolve years for dates without one from the season being scraped
+    /// instead of guessing around the current year.
+    pub fn with_season(mut self, season: Season) -> Self {
+        self.season = Some(season);
         self
     }
 
+    /// Read "now" from `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Pin "now" to a fixed instant (shorthand for a `FixedClock`).
+    pub fn with_current_time(self, current_time: DateTime<Utc>) -> Self {
+        self.with_clock(Arc::new(FixedClock::new(current_time)))
+    }
+
     /// Get current time (real or mocked)
     fn get_current_time(&self) -> DateTime<Utc> {
-        self.current_time.unwrap_or_else(Utc::now)
+        self.clock.now()
+    }
+
+    fn family_enabled(&self, family: DateFormatFamily) -> bool {
+        self.format_families.contains(&family)
     }
 
     /// Sophisticated multi-stage parsing with graceful degradation
@@ -86,146 +401,473 @@ impl DateTimeParser {
         time_str: &str,
     ) -> Result<(DateTime<Utc>, ParseMetadata), ScrapeError> {
         let current_year = self.get_current_time().year();
+        let original_source = format!("{date_str} {time_str}").trim().to_string();
+
+        // Stage 0: ISO strings with an explicit offset need no timezone assumptions
+        if let Some(result) = self.try_explicit_offset(date_str, time_str, &original_source) {
+            return Ok(result);
+        }
+
+        // Stage 0b: "Today 20:00", "Tomorrow", "This Saturday"
+        if let Some(result) = self.try_relative_date(date_str, time_str, &original_source) {
+            return result;
+        }
+
+        for locale in &self.locales {
+            let input = self.normalise(date_str, time_str, *locale);
+
+            if let Some((datetime, mut metadata)) =
+                self.parse_stages(&input, &original_source, current_year)
+            {
+                metadata.locale = Some(*locale);
+                return Ok((datetime, metadata));
+            }
+        }
+
+        Err(ScrapeError::InvalidDateTime(format!(
+            "Could not parse datetime: {date_str} {time_str} (tried exact, weekday-tolerant, and year variants)"
+        )))
+    }
+
+    /// Stages 1-3 for one normalised (locale-translated) input.
+    fn parse_stages(
+        &self,
+        input: &NormalisedInput,
+        original_source: &str,
+        current_year: i32,
+    ) -> Option<(DateTime<Utc>, ParseMetadata)> {
+        // A season context replaces the current-year guesswork for year-less dates
+        if let Some(result) = self.try_season_inference(input, original_source) {
+            return Some(result);
+        }
 
         // Stage 1: Try exact parsing with claimed weekday
-        if let Ok((datetime, metadata)) = self.try_exact_parsing(date_str, time_str, current_year) {
-            return Ok((datetime, metadata));
+        if let Ok((datetime, metadata)) =
+            self.try_exact_parsing(input, original_source, current_year)
+        {
+            return Some((datetime, metadata));
         }
 
         // Stage 2: Try weekday-tolerant parsing
         if let Ok((datetime, metadata)) =
-            self.try_weekday_tolerant_parsing(date_str, time_str, current_year)
+            self.try_weekday_tolerant_parsing(input, original_source, current_year)
         {
-            return Ok((datetime, metadata));
+            return Some((datetime, metadata));
         }
 
         // Stage 3: Try different year assumptions (for edge cases around year boundaries)
         for year_offset in [-1, 1] {
             let try_year = current_year + year_offset;
             if let Ok((datetime, mut metadata)) =
-                self.try_exact_parsing(date_str, time_str, try_year)
+                self.try_exact_parsing(input, original_source, try_year)
             {
-                metadata.parsing_strategy = ParsingStrategy::YearAssumption(try_year);
-                return Ok((datetime, metadata));
+                // Keep a timezone fallback visible; it matters more than the year guess
+                if metadata.parsing_strategy == ParsingStrategy::ExactMatch {
+                    metadata.parsing_strategy = ParsingStrategy::YearAssumption(try_year);
+                }
+                return Some((datetime, metadata));
             }
         }
 
-        Err(ScrapeError::InvalidDateTime(format!(
-            "Could not parse datetime: {date_str} {time_str} (tried exact, weekday-tolerant, and year variants)"
-        )))
+        None
     }
 
-    fn try_exact_parsing(
+    /// Pick the year for a year-less date from the season context.
+    ///
+    /// Every calendar year the season touches is tried. Candidates are ranked:
+    /// weekday agrees and the month belongs to that year of the season, then
+    /// weekday agrees in the other year (the source knows better than our
+    /// month boundaries), then the season's year despite a weekday mismatch.
+    fn try_season_inference(
+        &self,
+        input: &NormalisedInput,
+        original_source: &str,
+    ) -> Option<(DateTime<Utc>, ParseMetadata)> {
+        let season = self.season?;
+        if has_explicit_year(&input.date) {
+            return None;
+        }
+
+        let mut best: Option<(u8, i32, DateTime<Utc>, ParseMetadata)> = None;
+
+        for year in season.years() {
+            let candidate = self
+                .try_exact_parsing(input, original_source, year)
+                .or_else(|_| self.try_weekday_tolerant_parsing(input, original_source, year));
+            let Ok((datetime, metadata)) = candidate else {
+                continue;
+            };
+
+            let month = datetime.with_timezone(&self.default_timezone).month();
+            let weekday_consistent = metadata.weekday_mismatch.is_none();
+            let in_season = season.year_for_month(month) == year;
+            let rank = match (weekday_consistent, in_season) {
+                (true, true) => 0,
+                (true, false) => 1,
+                (false, true) => 2,
+                (false, false) => 3,
+            };
+
+            if best
+                .as_ref()
+                .is_none_or(|(best_rank, ..)| rank < *best_rank)
+            {
+                best = Some((rank, year, datetime, metadata));
+            }
+        }
+
+        best.map(|(_, year, datetime, mut metadata)| {
+            if !matches!(
+                metadata.parsing_strategy,
+                ParsingStrategy::TimezoneFallback(_)
+            ) {
+                metadata.parsing_strategy = ParsingStrategy::SeasonInference(year);
+            }
+            (datetime, metadata)
+        })
+    }
+
+    fn try_explicit_offset(
         &self,
         date_str: &str,
         time_str: &str,
+        original_source: &str,
+    ) -> Option<(DateTime<Utc>, ParseMetadata)> {
+        if !self.family_enabled(DateFormatFamily::Iso) || !time_str.trim().is_empty() {
+            return None;
+        }
+
+        let parsed = DateTime::parse_from_rfc3339(date_str.trim()).ok()?;
+        let metadata = ParseMetadata {
+            original_source: original_source.to_string(),
+            weekday_mismatch: None,
+            timezone_assumptions: format!("Explicit UTC offset {} in source", parsed.offset()),
+            parsing_strategy: ParsingStrategy::ExactMatch,
+            date_format: Some(MatchedFormat::rfc3339()),
+            ..Default::default()
+        };
+
+        Some((parsed.with_timezone(&Utc), metadata))
+    }
+
+    /// Resolve relative expressions against the current date in the default timezone.
+    ///
+    /// "This Saturday" (or a bare "Saturday") is the next Saturday including today;
+    /// "Next Saturday" is the first one strictly after today. The time may follow
+    /// the expression in `date_str` when the source puts both in one element.
+    /// Returns `None` when the text isn't a relative expression at all.
+    fn try_relative_date(
+        &self,
+        date_str: &str,
+        time_str: &str,
+        original_source: &str,
+    ) -> Option<Result<(DateTime<Utc>, ParseMetadata), ScrapeError>> {
+        let mut tokens: Vec<String> = date_str
+            .replace(',', " ")
+            .split_whitespace()
+            .map(|token| token.to_lowercase())
+            .collect();
+
+        // Only a trailing time, so a date-only "Sat 15" still reaches the absolute stages
+        let mut time_source = time_str.trim().to_string();
+        if time_source.is_empty()
+            && tokens.len() > 1
+            && tokens.last().is_some_and(|token| {
+                token.chars().any(|c| c.is_ascii_digit())
+                    && (token.contains(':') || token.ends_with("am") || token.ends_with("pm"))
+            })
+        {
+            time_source = tokens.pop().unwrap_or_default();
+        }
+
+        let days_ahead = match tokens.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+            ["today"] | ["tonight"] => 0,
+            ["tomorrow"] => 1,
+            ["this", weekday] | [weekday] => {
+                let target = self.relative_weekday(weekday)?;
+                self.days_until(target, false)
+            }
+            ["next", weekday] => {
+                let target = self.relative_weekday(weekday)?;
+                self.days_until(target, true)
+            }
+            _ => return None,
+        };
+
+        let (time, _) = normalise_time(
+            &time_source,
+            self.family_enabled(DateFormatFamily::TwelveHourClock),
+        );
+        let Ok(kick_off) = NaiveTime::parse_from_str(&time, "%H:%M") else {
+            return Some(Err(ScrapeError::InvalidDateTime(format!(
+                "Relative date without a usable time: {original_source}"
+            ))));
+        };
+
+        let now = self.get_current_time();
+        let today = now.with_timezone(&self.default_timezone).date_naive();
+        let date = today.checked_add_days(Days::new(days_ahead))?;
+
+        Some(self.localise(&date.and_time(kick_off)).map(|localised| {
+            let expression = tokens.join(" ");
+            let mut metadata = ParseMetadata {
+                original_source: original_source.to_string(),
+                weekday_mismatch: None,
+                timezone_assumptions: format!(
+                    "Resolved '{expression}' as {date} relative to {} in {}",
+                    now.format("%Y-%m-%d %H:%M UTC"),
+                    self.default_timezone
+                ),
+                parsing_strategy: ParsingStrategy::RelativeDate(expression),
+                ..Default::default()
+            };
+            let utc_dt = self.annotate(&localised, &mut metadata);
+            (utc_dt, metadata)
+        }))
+    }
+
+    /// Weekday named in any configured locale (English is always understood).
+    fn relative_weekday(&self, name: &str) -> Option<Weekday> {
+        self.locales
+            .iter()
+            .chain(std::iter::once(&Locale::English))
+            .find_map(|locale| locale.weekday_from_name(name))
+    }
+
+    /// Days from today (in the default timezone) until `target`.
+    fn days_until(&self, target: Weekday, strictly_after_today: bool) -> u64 {
+        let today = self
+            .get_current_time()
+            .with_timezone(&self.default_timezone)
+            .weekday();
+        let days = (7 + target.num_days_from_monday() - today.num_days_from_monday()) % 7;
+
+        if days == 0 && strictly_after_today {
+            7
+        } else {
+            u64::from(days)
+        }
+    }
+
+    /// Strip commas and (when enabled) ordinal suffixes, translate month and weekday
+    /// names from `locale`, and convert the time to 24-hour "HH:MM".
+    fn normalise(&self, date_str: &str, time_str: &str, locale: Locale) -> NormalisedInput {
+        let mut ordinal_suffix = false;
+        let tokens: Vec<String> = date_str
+            .replace(',', " ")
+            .split_whitespace()
+            .map(|token| {
+                if self.family_enabled(DateFormatFamily::OrdinalSuffix) {
+                    if let Some(day) = strip_ordinal_suffix(token) {
+                        ordinal_suffix = true;
+                        return day.to_string();
+                    }
+                }
+                token.to_string()
+            })
+            .collect();
+
+        let first_token = tokens.first().cloned();
+
+        // "15th of August" reads as "15 August" once the ordinal is gone
+        let date = if ordinal_suffix {
+            tokens
+                .into_iter()
+                .filter(|token| !token.eq_ignore_ascii_case("of"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        } else {
+            tokens.join(" ")
+        };
+
+        let (time, twelve_hour_clock) = normalise_time(
+            time_str,
+            self.family_enabled(DateFormatFamily::TwelveHourClock),
+        );
+
+        NormalisedInput {
+            date: locale.translate_date(&date),
+            time,
+            first_token,
+            ordinal_suffix,
+            twelve_hour_clock,
+        }
+    }
+
+    /// Parse `date` against every enabled family, appending `year` when the text has none.
+    fn match_families(
+        &self,
+        input: &NormalisedInput,
+        date: &str,
         year: i32,
-    ) -> Result<(DateTime<Utc>, ParseMetadata), ScrapeError> {
-        let datetime_str = format!("{date_str} {year} {time_str}");
-
-        // Try multiple common formats
-        let formats = [
-            "%a %b %d %Y %H:%M",  // "Sun Jul 27 2025 15:30"
-            "%a %b %e %Y %H:%M",  // "Sun Jul  7 2025 15:30" (single digit day)
-            "%A %B %d %Y %H:%M",  // "Sunday July 27 2025 15:30"
-            "%a, %b %d %Y %H:%M", // "Sun, Jul 27 2025 15:30"
-        ];
-
-        for format in &formats {
-            if let Ok(naive_dt) = NaiveDateTime::parse_from_str(&datetime_str, format) {
-                let timezone_dt = self
-                    .default_timezone
-                    .from_local_datetime(&naive_dt)
-                    .single()
-                    .ok_or_else(|| {
-                        ScrapeError::InvalidDateTime(format!(
-                            "Ambiguous local time: {datetime_str}"
-                        ))
-                    })?;
-
-                let utc_dt = timezone_dt.with_timezone(&Utc);
-
-                let metadata = ParseMetadata {
-                    original_source: format!("{date_str} {time_str}"),
-                    weekday_mismatch: None,
-                    timezone_assumptions: format!("Parsed as {} timezone", self.default_timezone),
-                    parsing_strategy: ParsingStrategy::ExactMatch,
-                };
-
-                return Ok((utc_dt, metadata));
+        with_weekday: bool,
+    ) -> Option<(NaiveDateTime, MatchedFormat)> {
+        let explicit_year = has_explicit_year(date);
+        let year_part = if explicit_year {
+            String::new()
+        } else {
+            year.to_string()
+        };
+        let datetime_str = [date, year_part.as_str(), input.time.as_str()]
+            .iter()
+            .filter(|part| !part.is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        for family in &self.format_families {
+            let (weekday_patterns, plain_patterns) = family.patterns();
+            let patterns = if with_weekday {
+                weekday_patterns
+            } else {
+                plain_patterns
+            };
+
+            for pattern in patterns {
+                if let Ok(naive_dt) = NaiveDateTime::parse_from_str(&datetime_str, pattern) {
+                    let matched = MatchedFormat {
+                        family: *family,
+                        pattern: pattern.to_string(),
+                        ordinal_suffix: input.ordinal_suffix,
+                        twelve_hour_clock: input.twelve_hour_clock,
+                    };
+                    return Some((naive_dt, matched));
+                }
             }
         }
 
+        None
+    }
+
+    /// Place a naive local time in the default timezone, resolving clock changes
+    /// by the DST policy and falling back to the fallback timezone when told to.
+    fn localise(&self, naive_dt: &NaiveDateTime) -> Result<Localised, ScrapeError> {
+        if let Some((local, dst_resolution)) =
+            resolve_local_time(self.default_timezone, naive_dt, self.dst_policy)
+        {
+            return Ok(Localised {
+                local,
+                dst_resolution,
+                used_fallback: false,
+            });
+        }
+
+        // The fallback zone is the last resort, so it must always produce an instant
+        resolve_local_time(self.fallback_timezone, naive_dt, DstPolicy::ShiftForward)
+            .map(|(local, dst_resolution)| Localised {
+                local,
+                dst_resolution,
+                used_fallback: true,
+            })
+            .ok_or_else(|| {
+                ScrapeError::InvalidDateTime(format!(
+                    "Local time {naive_dt} does not exist in {} or {}",
+                    self.default_timezone, self.fallback_timezone
+                ))
+            })
+    }
+
+    /// Copy the localisation decisions into the metadata and return the UTC instant.
+    fn annotate(&self, localised: &Localised, metadata: &mut ParseMetadata) -> DateTime<Utc> {
+        metadata.dst_resolution = localised.dst_resolution.clone();
+
+        if localised.used_fallback {
+            metadata.timezone_assumptions = format!(
+                "Local time is skipped or repeated in {}; parsed as {} timezone",
+                self.default_timezone, self.fallback_timezone
+            );
+            metadata.parsing_strategy =
+                ParsingStrategy::TimezoneFallback(self.fallback_timezone.to_string());
+        }
+
+        localised.local.with_timezone(&Utc)
+    }
+
+    fn try_exact_parsing(
+        &self,
+        input: &NormalisedInput,
+        original_source: &str,
+        year: i32,
+    ) -> Result<(DateTime<Utc>, ParseMetadata), ScrapeError> {
+        // Dates with a weekday must agree with it; dates without one have nothing to disagree with
+        let matched = self
+            .match_families(input, &input.date, year, true)
+            .or_else(|| self.match_families(input, &input.date, year, false));
+
+        if let Some((naive_dt, date_format)) = matched {
+            let localised = self.localise(&naive_dt)?;
+
+            let mut metadata = ParseMetadata {
+                original_source: original_source.to_string(),
+                weekday_mismatch: None,
+                timezone_assumptions: format!("Parsed as {} timezone", self.default_timezone),
+                parsing_strategy: ParsingStrategy::ExactMatch,
+                date_format: Some(date_format),
+                ..Default::default()
+            };
+            let utc_dt = self.annotate(&localised, &mut metadata);
+
+            return Ok((utc_dt, metadata));
+        }
+
         Err(ScrapeError::InvalidDateTime(format!(
-            "No format matched: {datetime_str}"
+            "No format matched: {} {year} {}",
+            input.date, input.time
         )))
     }
 
     fn try_weekday_tolerant_parsing(
         &self,
-        date_str: &str,
-        time_str: &str,
+        input: &NormalisedInput,
+        original_source: &str,
         year: i32,
     ) -> Result<(DateTime<Utc>, ParseMetadata), ScrapeError> {
         // Extract weekday and date parts
-        let parts: Vec<&str> = date_str.split_whitespace().collect();
-        if parts.len() < 3 {
+        let parts: Vec<&str> = input.date.split_whitespace().collect();
+        if parts.len() < 2 {
             return Err(ScrapeError::InvalidDateTime(
                 "Insufficient date parts".to_string(),
             ));
         }
 
-        let claimed_weekday = parts[0];
+        // Report the weekday as the source wrote it ("Samedi", not "Sat")
+        let claimed_weekday = input.first_token.as_deref().unwrap_or(parts[0]);
         let date_without_weekday = parts[1..].join(" ");
 
         // Try parsing without weekday validation
-        let datetime_str = format!("{date_without_weekday} {year} {time_str}");
-
-        let formats_without_weekday = [
-            "%b %d %Y %H:%M", // "Jul 27 2025 15:30"
-            "%b %e %Y %H:%M", // "Jul  7 2025 15:30"
-            "%B %d %Y %H:%M", // "July 27 2025 15:30"
-        ];
-
-        for format in &formats_without_weekday {
-            if let Ok(naive_dt) = NaiveDateTime::parse_from_str(&datetime_str, format) {
-                let timezone_dt = self
-                    .default_timezone
-                    .from_local_datetime(&naive_dt)
-                    .single()
-                    .ok_or_else(|| {
-                        ScrapeError::InvalidDateTime(format!(
-                            "Ambiguous local time: {datetime_str}"
-                        ))
-                    })?;
-
-                let utc_dt = timezone_dt.with_timezone(&Utc);
-
-                // Check if weekday actually matches
-                let actual_weekday = timezone_dt.weekday();
-                let weekday_mismatch = if !self.weekday_matches(claimed_weekday, actual_weekday) {
-                    Some(WeekdayMismatch {
-                        claimed_weekday: claimed_weekday.to_string(),
-                        actual_weekday: self.weekday_to_string(actual_weekday).to_string(),
-                        date: date_without_weekday.clone(),
-                    })
-                } else {
-                    None
-                };
-
-                let metadata = ParseMetadata {
-                    original_source: format!("{date_str} {time_str}"),
-                    weekday_mismatch,
-                    timezone_assumptions: format!("Parsed as {} timezone", self.default_timezone),
-                    parsing_strategy: ParsingStrategy::WeekdayTolerant,
-                };
-
-                return Ok((utc_dt, metadata));
-            }
+        if let Some((naive_dt, date_format)) =
+            self.match_families(input, &date_without_weekday, year, false)
+        {
+            let localised = self.localise(&naive_dt)?;
+
+            // Check if weekday actually matches (against the date as written)
+            let actual_weekday = naive_dt.weekday();
+            let weekday_mismatch = if !self.weekday_matches(claimed_weekday, actual_weekday) {
+                Some(WeekdayMismatch {
+                    claimed_weekday: claimed_weekday.to_string(),
+                    actual_weekday: self.weekday_to_string(actual_weekday).to_string(),
+                    date: date_without_weekday.clone(),
+                })
+            } else {
+                None
+            };
+
+            let mut metadata = ParseMetadata {
+                original_source: original_source.to_string(),
+                weekday_mismatch,
+                timezone_assumptions: format!("Parsed as {} timezone", self.default_timezone),
+                parsing_strategy: ParsingStrategy::WeekdayTolerant,
+                date_format: Some(date_format),
+                ..Default::default()
+            };
+            let utc_dt = self.annotate(&localised, &mut metadata);
+
+            return Ok((utc_dt, metadata));
         }
 
         Err(ScrapeError::InvalidDateTime(format!(
-            "Weekday-tolerant parsing failed: {datetime_str}"
+            "Weekday-tolerant parsing failed: {date_without_weekday} {year} {}",
+            input.time
         )))
     }
 
@@ -233,8 +875,15 @@ impl DateTimeParser {
         let claimed_lower = claimed.to_lowercase();
         let actual_str = self.weekday_to_string(actual).to_lowercase();
 
+        // Localised names ("Samedi", "Sa.") match through the configured locale packs
+        let locale_match = self
+            .locales
+            .iter()
+            .any(|locale| locale.weekday_from_name(claimed) == Some(actual));
+
         // Check both abbreviated and full forms
-        claimed_lower == actual_str ||
+        locale_match ||
+            claimed_lower == actual_str ||
             claimed_lower == actual_str[..3] || // "sun" matches "sunday"
             claimed_lower.starts_with(&actual_str[..3])
     }
@@ -252,6 +901,118 @@ impl DateTimeParser {
     }
 }
 
+/// "15th" → "15", "1st" → "1"; `None` when the token has no ordinal suffix.
+fn strip_ordinal_suffix(token: &str) -> Option<&str> {
+    let digits_end = token.find(|c: char| !c.is_ascii_digit())?;
+    let (day, suffix) = token.split_at(digits_end);
+    let is_ordinal = ["st", "nd", "rd", "th"]
+        .iter()
+        .any(|ordinal| suffix.eq_ignore_ascii_case(ordinal));
+
+    (!day.is_empty() && is_ordinal).then_some(day)
+}
+
+/// A 4-digit year, a fully numeric d/m/y date or an ISO date means the year is already present.
+fn has_explicit_year(date: &str) -> bool {
+    date.split_whitespace().any(|token| {
+        let starts_with_year = token.len() >= 4 && token[..4].chars().all(|c| c.is_ascii_digit());
+        let is_year = starts_with_year && (token.len() == 4 || token[4..].starts_with('-'));
+        let numeric_parts = token
+            .split(['/', '.', '-'])
+            .filter(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+            .count();
+        is_year || numeric_parts >= 3
+    })
+}
+
+/// Place `naive` in `tz`, resolving a skipped or repeated local time by `policy`.
+///
+/// Returns `None` only for `DstPolicy::Fallback` at a clock change.
+fn resolve_local_time(
+    tz: Tz,
+    naive: &NaiveDateTime,
+    policy: DstPolicy,
+) -> Option<(DateTime<Tz>, Option<DstResolution>)> {
+    let (transition, earlier, later) = match tz.from_local_datetime(naive) {
+        LocalResult::Single(local) => return Some((local, None)),
+        LocalResult::Ambiguous(first, second) => (DstTransition::Ambiguous, first, second),
+        LocalResult::None => {
+            // Read the time with the offsets either side of the gap; clock changes
+            // are months apart, so a day away is safely on the right side
+            let offset_at = |utc: NaiveDateTime| tz.offset_from_utc_datetime(&utc).fix();
+            let before = offset_at(*naive - Duration::days(1));
+            let after = offset_at(*naive + Duration::days(1));
+            let instant = |offset: chrono::FixedOffset| {
+                tz.from_utc_datetime(&(*naive - Duration::seconds(offset.local_minus_utc().into())))
+            };
+            // The post-change offset is larger, so it gives the earlier instant
+            (DstTransition::Skipped, instant(after), instant(before))
+        }
+    };
+
+    let local = match (policy, transition) {
+        (DstPolicy::Fallback, _) => return None,
+        (DstPolicy::Earliest, _) => earlier,
+        (DstPolicy::Latest, _) => later,
+        (DstPolicy::ShiftForward, DstTransition::Ambiguous) => earlier,
+        (DstPolicy::ShiftForward, DstTransition::Skipped) => later,
+    };
+
+    let resolution = DstResolution {
+        transition,
+        policy,
+        local_time: *naive,
+        timezone: tz.to_string(),
+        resolved_offset: local.offset().fix().to_string(),
+    };
+
+    Some((local, Some(resolution)))
+}
+
+/// Convert "3pm", "7.45 PM", "15.00" or "15h00" to "HH:MM".
+///
+/// Returns the time unchanged when it can't be understood so that chrono
+/// reports the failure, plus whether a 12-hour clock was used.
+fn normalise_time(time_str: &str, allow_twelve_hour: bool) -> (String, bool) {
+    let compact: String = time_str
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .replace("a.m.", "am")
+        .replace("p.m.", "pm");
+
+    let (clock, meridiem) = if let Some(clock) = compact.strip_suffix("pm") {
+        (clock, Some(12))
+    } else if let Some(clock) = compact.strip_suffix("am") {
+        (clock, Some(0))
+    } else {
+        (compact.as_str(), None)
+    };
+
+    if meridiem.is_some() && !allow_twelve_hour {
+        return (time_str.trim().to_string(), false);
+    }
+
+    let mut numbers = clock.split([':', '.', 'h']);
+    let hour = numbers.next().and_then(|h| h.parse::<u32>().ok());
+    let minute = match numbers.next() {
+        Some("") | None => Some(0),
+        Some(m) if m.len() == 2 => m.parse::<u32>().ok(),
+        Some(_) => None,
+    };
+
+    match (hour, minute, meridiem) {
+        (Some(hour @ 1..=12), Some(minute), Some(offset)) => {
+            (format!("{:02}:{minute:02}", hour % 12 + offset), true)
+        }
+        (Some(hour), Some(minute), None) if numbers.next().is_none() => {
+            (format!("{hour:02}:{minute:02}"), false)
+        }
+        _ => (time_str.trim().to_string(), false),
+    }
+}
+
 impl ParseMetadata {
     pub fn to_timezone_info(&self) -> String {
         let mut info = format!("{} - {}", self.timezone_assumptions, self.original_source);
@@ -274,6 +1035,12 @@ impl ParseMetadata {
             ParsingStrategy::TimezoneFallback(tz) => {
                 info.push_str(&format!(" [fallback timezone: {tz}]"));
             }
+            ParsingStrategy::RelativeDate(expression) => {
+                info.push_str(&format!(" [relative date: {expression}]"));
+            }
+            ParsingStrategy::SeasonInference(year) => {
+                info.push_str(&format!(" [season year {year}]"));
+            }
         }
 
         info
@@ -281,7 +1048,11 @@ impl ParseMetadata {
 
     pub fn has_data_quality_issues(&self) -> bool {
         self.weekday_mismatch.is_some()
-            || !matches!(self.parsing_strategy, ParsingStrategy::ExactMatch)
+            || self.dst_resolution.is_some()
+            || !matches!(
+                self.parsing_strategy,
+                ParsingStrategy::ExactMatch | ParsingStrategy::SeasonInference(_)
+            )
     }
 }
 
@@ -292,6 +1063,10 @@ impl fmt::Display for ParsingStrategy {
             ParsingStrategy::WeekdayTolerant => write!(f, "Weekday Tolerant"),
             ParsingStrategy::YearAssumption(year) => write!(f, "Year Assumption ({year})"),
             ParsingStrategy::TimezoneFallback(tz) => write!(f, "Timezone Fallback ({tz})"),
+            ParsingStrategy::RelativeDate(expression) => {
+                write!(f, "Relative Date ({expression})")
+            }
+            ParsingStrategy::SeasonInference(year) => write!(f, "Season Inference ({year})"),
         }
     }
 }
@@ -345,7 +1120,9 @@ mod tests {
 
     #[test]
     fn test_timezone_info_generation() {
-        let parser = create_london_parser();
+        // Jul 27 is a Sunday in 2025 (but a Monday in 2026)
+        let mock_now = Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now);
 
         let result = parser.parse_with_weekday_tolerance("Mon Jul 27", "15:30");
         assert!(result.is_ok());
@@ -415,6 +1192,420 @@ mod tests {
         assert!(metadata.weekday_mismatch.is_none());
     }
 
+    #[test]
+    fn test_day_first_format() {
+        // Aug 15, 2025 is a Friday; Arsenal's display text is day-first
+        let mock_now = Utc.with_ymd_and_hms(2025, 7, 27, 12, 0, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now);
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("Fri 15 Aug", "15:00")
+            .expect("Day-first text should parse");
+
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 8, 15, 14, 0, 0).unwrap()
+        );
+        assert_eq!(metadata.parsing_strategy, ParsingStrategy::ExactMatch);
+        let format = metadata.date_format.expect("Format should be recorded");
+        assert_eq!(format.family, DateFormatFamily::DayFirst);
+        assert!(!format.ordinal_suffix);
+    }
+
+    #[test]
+    fn test_ordinal_suffix_and_twelve_hour_clock() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 7, 27, 12, 0, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now);
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("15th of August", "3pm")
+            .expect("Ordinal date with 12-hour time should parse");
+
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 8, 15, 14, 0, 0).unwrap()
+        );
+        let format = metadata.date_format.unwrap();
+        assert_eq!(format.family, DateFormatFamily::DayFirst);
+        assert!(format.ordinal_suffix);
+        assert!(format.twelve_hour_clock);
+
+        let (datetime, _) = parser
+            .parse_with_weekday_tolerance("Saturday, August 16th", "7:45 PM")
+            .expect("Month-first ordinal should parse");
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 8, 16, 18, 45, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_numeric_dates_carry_their_own_year() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 7, 27, 12, 0, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now);
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("15/01/2026", "20:00")
+            .expect("Numeric date should parse");
+
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2026, 1, 15, 20, 0, 0).unwrap()
+        );
+        assert_eq!(metadata.parsing_strategy, ParsingStrategy::ExactMatch);
+        assert_eq!(
+            metadata.date_format.unwrap().family,
+            DateFormatFamily::NumericDayFirst
+        );
+    }
+
+    #[test]
+    fn test_numeric_month_first_is_opt_in() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 7, 27, 12, 0, 0).unwrap();
+
+        // Default order reads 05/08 the UK way
+        let uk_parser = create_test_parser_with_fixed_date(mock_now);
+        let (uk_datetime, _) = uk_parser
+            .parse_with_weekday_tolerance("05/08/2025", "15:00")
+            .unwrap();
+        assert_eq!(uk_datetime.month(), 8);
+
+        // A US source prefers month-first
+        let us_parser = create_test_parser_with_fixed_date(mock_now).with_format_families(vec![
+            DateFormatFamily::NumericMonthFirst,
+            DateFormatFamily::TwelveHourClock,
+        ]);
+        let (us_datetime, metadata) = us_parser
+            .parse_with_weekday_tolerance("05/08/2025", "3:00 pm")
+            .unwrap();
+        assert_eq!(us_datetime.month(), 5);
+        assert_eq!(
+            metadata.date_format.unwrap().family,
+            DateFormatFamily::NumericMonthFirst
+        );
+
+        // Families that aren't enabled are never tried
+        assert!(us_parser
+            .parse_with_weekday_tolerance("Sat 15 Aug", "15:00")
+            .is_err());
+    }
+
+    #[test]
+    fn test_iso_strings() {
+        let parser = create_london_parser();
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("2025-08-15T14:00:00.000Z", "")
+            .expect("RFC 3339 should parse");
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 8, 15, 14, 0, 0).unwrap()
+        );
+        assert!(metadata
+            .timezone_assumptions
+            .contains("Explicit UTC offset"));
+
+        // Without an offset the default timezone applies
+        let (local, metadata) = parser
+            .parse_with_weekday_tolerance("2025-08-15T15:00", "")
+            .expect("Local ISO should parse");
+        assert_eq!(local, datetime);
+        assert_eq!(metadata.date_format.unwrap().family, DateFormatFamily::Iso);
+    }
+
+    #[test]
+    fn test_normalise_time() {
+        assert_eq!(normalise_time("3pm", true), ("15:00".to_string(), true));
+        assert_eq!(normalise_time("12 a.m.", true), ("00:00".to_string(), true));
+        assert_eq!(normalise_time("7.45 PM", true), ("19:45".to_string(), true));
+        assert_eq!(normalise_time("15h00", true), ("15:00".to_string(), false));
+        assert_eq!(normalise_time("3pm", false), ("3pm".to_string(), false));
+        assert_eq!(normalise_time("25:99", true), ("25:99".to_string(), false));
+    }
+
+    #[test]
+    fn test_locale_month_and_weekday_names() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 7, 27, 12, 0, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now).with_locales(vec![
+            Locale::English,
+            Locale::Afrikaans,
+            Locale::French,
+        ]);
+
+        // Aug 16, 2025 is a Saturday
+        let expected = Utc.with_ymd_and_hms(2025, 8, 16, 14, 0, 0).unwrap();
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("Saterdag 16 Augustus", "15:00")
+            .expect("Afrikaans date should parse");
+        assert_eq!(datetime, expected);
+        assert_eq!(metadata.locale, Some(Locale::Afrikaans));
+        assert_eq!(metadata.parsing_strategy, ParsingStrategy::ExactMatch);
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("samedi 16 août", "15h00")
+            .expect("French date should parse");
+        assert_eq!(datetime, expected);
+        assert_eq!(metadata.locale, Some(Locale::French));
+
+        let (_, metadata) = parser
+            .parse_with_weekday_tolerance("Sat 16 Aug", "15:00")
+            .unwrap();
+        assert_eq!(metadata.locale, Some(Locale::English));
+    }
+
+    #[test]
+    fn test_locale_weekday_mismatch_detection() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 7, 27, 12, 0, 0).unwrap();
+        let parser =
+            create_test_parser_with_fixed_date(mock_now).with_locales(vec![Locale::German]);
+
+        // Aug 16, 2025 is a Saturday, not a Sunday
+        let (_, metadata) = parser
+            .parse_with_weekday_tolerance("So. 16. August", "15:00")
+            .expect("German date should parse with tolerance");
+
+        assert_eq!(metadata.parsing_strategy, ParsingStrategy::WeekdayTolerant);
+        let mismatch = metadata
+            .weekday_mismatch
+            .expect("Mismatch should be recorded");
+        assert_eq!(mismatch.claimed_weekday, "So.");
+        assert_eq!(mismatch.actual_weekday, "Saturday");
+
+        assert!(parser.weekday_matches("Samstag", Weekday::Sat));
+        assert!(!parser.weekday_matches("Samedi", Weekday::Sat)); // French not enabled
+    }
+
+    #[test]
+    fn test_relative_dates() {
+        // Thursday 14 Aug 2025, 23:30 UTC is already Friday 00:30 in London
+        let mock_now = Utc.with_ymd_and_hms(2025, 8, 14, 23, 30, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now);
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("Today 20:00", "")
+            .expect("Today should parse");
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 8, 15, 19, 0, 0).unwrap()
+        );
+        assert_eq!(
+            metadata.parsing_strategy,
+            ParsingStrategy::RelativeDate("today".to_string())
+        );
+
+        let (datetime, _) = parser
+            .parse_with_weekday_tolerance("Tomorrow", "3pm")
+            .unwrap();
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 8, 16, 14, 0, 0).unwrap()
+        );
+
+        let (datetime, _) = parser
+            .parse_with_weekday_tolerance("This Saturday", "12:30")
+            .unwrap();
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 8, 16, 11, 30, 0).unwrap()
+        );
+
+        // "This Friday" is today; "Next Friday" is a week away
+        let (this_friday, _) = parser
+            .parse_with_weekday_tolerance("This Friday", "20:00")
+            .unwrap();
+        let (next_friday, _) = parser
+            .parse_with_weekday_tolerance("Next Friday", "20:00")
+            .unwrap();
+        assert_eq!(this_friday.date_naive().day(), 15);
+        assert_eq!(next_friday.date_naive().day(), 22);
+    }
+
+    #[test]
+    fn test_relative_date_requires_time() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 8, 14, 12, 0, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now);
+
+        assert!(parser
+            .parse_with_weekday_tolerance("Tomorrow", "TBC")
+            .is_err());
+        // Not relative: falls through to the absolute stages
+        assert!(parser
+            .parse_with_weekday_tolerance("Sat 16 Aug", "15:00")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_relative_date_only_takes_a_time_token() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 8, 14, 12, 0, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now);
+
+        // A trailing time in the date string is used...
+        let (datetime, _) = parser
+            .parse_with_weekday_tolerance("Saturday 3pm", "")
+            .unwrap();
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 8, 16, 14, 0, 0).unwrap()
+        );
+
+        // ...but a day of the month isn't a kick-off at 15:00 this Saturday
+        let result = parser.parse_with_weekday_tolerance("Sat 15", "");
+        assert!(!matches!(
+            result,
+            Ok((
+                _,
+                ParseMetadata {
+                    parsing_strategy: ParsingStrategy::RelativeDate(_),
+                    ..
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_dst_ambiguous_time() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 10, 1, 12, 0, 0).unwrap();
+
+        // Clocks go back at 02:00 BST on Sun 26 Oct 2025, so 01:30 happens twice
+        let parser = create_test_parser_with_fixed_date(mock_now);
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("Sun 26 Oct", "01:30")
+            .expect("Ambiguous time should not vanish");
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 10, 26, 0, 30, 0).unwrap()
+        );
+        let resolution = metadata.dst_resolution.expect("Resolution recorded");
+        assert_eq!(resolution.transition, DstTransition::Ambiguous);
+        assert_eq!(resolution.resolved_offset, "+01:00");
+
+        let parser =
+            create_test_parser_with_fixed_date(mock_now).with_dst_policy(DstPolicy::Latest);
+        let (datetime, _) = parser
+            .parse_with_weekday_tolerance("Sun 26 Oct", "01:30")
+            .unwrap();
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 10, 26, 1, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dst_skipped_time() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+
+        // Clocks go forward at 01:00 GMT on Sun 30 Mar 2025, so 01:30 never happens
+        let parser = create_test_parser_with_fixed_date(mock_now);
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("Sun 30 Mar", "01:30")
+            .expect("Skipped time should not vanish");
+        // Shifted forward to 02:30 BST
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 3, 30, 1, 30, 0).unwrap()
+        );
+        assert_eq!(
+            metadata.dst_resolution.unwrap().transition,
+            DstTransition::Skipped
+        );
+
+        let parser =
+            create_test_parser_with_fixed_date(mock_now).with_dst_policy(DstPolicy::Earliest);
+        let (datetime, _) = parser
+            .parse_with_weekday_tolerance("Sun 30 Mar", "01:30")
+            .unwrap();
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 3, 30, 0, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_timezone_fallback() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let parser = create_test_parser_with_fixed_date(mock_now)
+            .with_dst_policy(DstPolicy::Fallback)
+            .with_fallback_timezone(chrono_tz::UTC);
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("Sun 30 Mar", "01:30")
+            .expect("Fallback timezone should parse");
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2025, 3, 30, 1, 30, 0).unwrap()
+        );
+        assert_eq!(
+            metadata.parsing_strategy,
+            ParsingStrategy::TimezoneFallback("UTC".to_string())
+        );
+        assert!(metadata.dst_resolution.is_none());
+
+        // Ordinary times never touch the fallback
+        let (_, metadata) = parser
+            .parse_with_weekday_tolerance("Sun 30 Mar", "15:00")
+            .unwrap();
+        assert_eq!(metadata.parsing_strategy, ParsingStrategy::ExactMatch);
+    }
+
+    #[test]
+    fn test_season_inference_resolves_next_year() {
+        // Scraped in August 2025: "Sat 17 Jan" belongs to the 2025/26 season, so 2026
+        let mock_now = Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap();
+        let parser =
+            create_test_parser_with_fixed_date(mock_now).with_season(Season::football(2025));
+
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("Sat 17 Jan", "15:00")
+            .expect("Season inference should parse");
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2026, 1, 17, 15, 0, 0).unwrap()
+        );
+        assert_eq!(
+            metadata.parsing_strategy,
+            ParsingStrategy::SeasonInference(2026)
+        );
+        assert!(metadata.weekday_mismatch.is_none());
+        assert!(!metadata.has_data_quality_issues());
+
+        // Without a season the current year wins, with a weekday mismatch
+        let (datetime, metadata) = create_test_parser_with_fixed_date(mock_now)
+            .parse_with_weekday_tolerance("Sat 17 Jan", "15:00")
+            .unwrap();
+        assert_eq!(datetime.year(), 2025);
+        assert!(metadata.weekday_mismatch.is_some());
+    }
+
+    #[test]
+    fn test_season_inference_prefers_weekday_consistency() {
+        let mock_now = Utc.with_ymd_and_hms(2025, 8, 1, 12, 0, 0).unwrap();
+        let parser =
+            create_test_parser_with_fixed_date(mock_now).with_season(Season::football(2025));
+
+        // June 2026 is off-season; Wed 10 Jun is a Wednesday in 2026 only
+        let (datetime, _) = parser
+            .parse_with_weekday_tolerance("Wed 10 Jun", "19:45")
+            .unwrap();
+        assert_eq!(datetime.year(), 2026);
+
+        // Sat 26 Jul is a Saturday in 2025 (pre-season), not 2026
+        let (datetime, metadata) = parser
+            .parse_with_weekday_tolerance("Sat 26 Jul", "15:00")
+            .unwrap();
+        assert_eq!(datetime.year(), 2025);
+        assert_eq!(
+            metadata.parsing_strategy,
+            ParsingStrategy::SeasonInference(2025)
+        );
+
+        // Explicit years are never second-guessed
+        let (_, metadata) = parser
+            .parse_with_weekday_tolerance("Sat 17 Jan 2026", "15:00")
+            .unwrap();
+        assert_eq!(metadata.parsing_strategy, ParsingStrategy::ExactMatch);
+    }
+
     #[test]
     fn test_weekday_matching_logic() {
         let parser = create_london_parser();
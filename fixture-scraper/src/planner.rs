@@ -0,0 +1,352 @@
+//! # Watch-Party Planner
+//!
+//! A group following several teams can only host one watch party at a time.
+//! [`WatchPartyPlanner`] looks across every followed team's usable fixtures,
+//! finds events that overlap or run back-to-back, and suggests which one to
+//! host.
+//!
+//! ## Choosing the host
+//!
+//! [`WatchPriorities`] lists teams and competitions from most to least
+//! important. The fixture whose team ranks higher wins; on a tie the higher
+//! competition wins; otherwise the earlier kick-off does. Unlisted teams and
+//! competitions rank below every listed one.
+//!
+//! Conflicts are reported in the viewer's timezone, since "back-to-back on
+//! Saturday evening" means something different in London and Johannesburg.
+
+use crate::dedupe::normalise_team;
+use crate::validation::ValidatedFixture;
+use crate::{Fixture, ScrapeError};
+use chrono::Duration;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Teams and competitions in order of importance, most important first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct WatchPriorities {
+    /// Team names as displayed, e.g. "Arsenal Women" or "South Africa"
+    pub teams: Vec<String>,
+    /// Competition keywords, e.g. "Champions League" or "Cup"
+    pub competitions: Vec<String>,
+}
+
+impl WatchPriorities {
+    pub fn from_json(json: &str) -> Result<Self, ScrapeError> {
+        serde_json::from_str(json)
+            .map_err(|e| ScrapeError::Parse(format!("Invalid watch priorities: {e}")))
+    }
+
+    /// Position of the fixture's best-ranked team (either side), if listed.
+    pub fn team_rank(&self, fixture: &Fixture) -> Option<usize> {
+        let sides = [
+            normalise_team(&fixture.team_label()),
+            normalise_team(&fixture.opponent),
+        ];
+        self.teams
+            .iter()
+            .position(|team| sides.contains(&normalise_team(team)))
+    }
+
+    /// Position of the fixture's competition, if listed.
+    pub fn competition_rank(&self, fixture: &Fixture) -> Option<usize> {
+        self.competitions
+            .iter()
+            .position(|listed| fixture.competition_matches(listed))
+    }
+}
+
+/// How two events collide.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConflictKind {
+    /// The second starts before the first can have finished
+    Overlap,
+    /// The second starts within the planner's buffer after the first ends
+    BackToBack,
+}
+
+impl fmt::Display for ConflictKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictKind::Overlap => write!(f, "overlaps"),
+            ConflictKind::BackToBack => write!(f, "runs straight into"),
+        }
+    }
+}
+
+/// Which fixture of a conflict to host.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Host {
+    First,
+    Second,
+}
+
+/// Two fixtures that can't both get a watch party, and which to pick.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Conflict {
+    /// The earlier kick-off
+    pub first: Fixture,
+    pub second: Fixture,
+    pub kind: ConflictKind,
+    /// Time from the first's latest possible end to the second's kick-off (negative when overlapping)
+    pub gap_minutes: i64,
+    pub host: Host,
+    /// Why the host was suggested
+    pub reason: String,
+}
+
+impl Conflict {
+    pub fn hosted(&self) -> &Fixture {
+        match self.host {
+            Host::First => &self.first,
+            Host::Second => &self.second,
+        }
+    }
+
+    pub fn skipped(&self) -> &Fixture {
+        match self.host {
+            Host::First => &self.second,
+            Host::Second => &self.first,
+        }
+    }
+
+    /// One-line summary with kick-offs in the viewer's timezone.
+    pub fn describe(&self, timezone: Tz) -> String {
+        let local = |fixture: &Fixture| {
+            fixture
+                .datetime
+                .with_timezone(&timezone)
+                .format("%a %d %b %H:%M")
+                .to_string()
+        };
+
+        format!(
+            "{} ({}) {} {} ({}); host {} ({})",
+            self.first.title(),
+            local(&self.first),
+            self.kind,
+            self.second.title(),
+            local(&self.second),
+            self.hosted().title(),
+            self.reason
+        )
+    }
+}
+
+/// Finds watch-party conflicts across followed teams.
+#[derive(Debug, Clone)]
+pub struct WatchPartyPlanner {
+    pub timezone: Tz,
+    /// Events closer together than this count as back-to-back
+    pub buffer: Duration,
+    pub priorities: WatchPriorities,
+}
+
+impl WatchPartyPlanner {
+    /// Planner for a viewer in `timezone`, with an hour between events and no priorities.
+    pub fn new(timezone: Tz) -> Self {
+        Self {
+            timezone,
+            buffer: Duration::hours(1),
+            priorities: WatchPriorities::default(),
+        }
+    }
+
+    pub fn with_buffer(mut self, buffer: Duration) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    pub fn with_priorities(mut self, priorities: WatchPriorities) -> Self {
+        self.priorities = priorities;
+        self
+    }
+
+    /// Every pair of usable fixtures from different teams that collide, earliest first.
+    pub fn find_conflicts(&self, fixtures: &[ValidatedFixture]) -> Vec<Conflict> {
+        let mut usable: Vec<&Fixture> = fixtures
+            .iter()
+            .filter(|validated| validated.is_usable() && validated.fixture.status.is_active())
+            .map(|validated| &validated.fixture)
+            .collect();
+        usable.sort_by_key(|fixture| fixture.datetime);
+
+        let mut conflicts = Vec::new();
+        for (i, first) in usable.iter().enumerate() {
+            let (_, first_end) = first.time_window();
+
+            for second in &usable[i + 1..] {
+                let gap = second.datetime - first_end;
+                if gap >= self.buffer {
+                    // Sorted by kick-off, so nothing later can collide either
+                    break;
+                }
+                if normalise_team(&first.team_label()) == normalise_team(&second.team_label()) {
+                    // A team clashing with itself is a data problem for validation
+                    continue;
+                }
+
+                let kind = if gap < Duration::zero() {
+                    ConflictKind::Overlap
+                } else {
+                    ConflictKind::BackToBack
+                };
+                let (host, reason) = self.choose_host(first, second);
+                conflicts.push(Conflict {
+                    first: (*first).clone(),
+                    second: (*second).clone(),
+                    kind,
+                    gap_minutes: gap.num_minutes(),
+                    host,
+                    reason,
+                });
+            }
+        }
+
+        conflicts
+    }
+
+    fn choose_host(&self, first: &Fixture, second: &Fixture) -> (Host, String) {
+        let priorities = &self.priorities;
+        // Listed beats unlisted; lower position beats higher
+        let rank = |rank: Option<usize>| rank.unwrap_or(usize::MAX);
+        let pick = |ordering: Ordering| match ordering {
+            Ordering::Greater => Host::Second,
+            _ => Host::First,
+        };
+        let describe_rank = |rank: Option<usize>| match rank {
+            Some(position) => format!("#{}", position + 1),
+            None => "unlisted".to_string(),
+        };
+
+        let (first_team, second_team) = (priorities.team_rank(first), priorities.team_rank(second));
+        if rank(first_team) != rank(second_team) {
+            let host = pick(rank(first_team).cmp(&rank(second_team)));
+            return (
+                host,
+                format!(
+                    "team priority {} vs {}",
+                    describe_rank(first_team),
+                    describe_rank(second_team)
+                ),
+            );
+        }
+
+        let (first_competition, second_competition) = (
+            priorities.competition_rank(first),
+            priorities.competition_rank(second),
+        );
+        if rank(first_competition) != rank(second_competition) {
+            let host = pick(rank(first_competition).cmp(&rank(second_competition)));
+            return (
+                host,
+                format!(
+                    "competition priority {} vs {}",
+                    describe_rank(first_competition),
+                    describe_rank(second_competition)
+                ),
+            );
+        }
+
+        (Host::First, "equal priority; earlier kick-off".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sport::Sport;
+    use crate::test_support::{fixture, now, utc};
+
+    fn validated(
+        team: &str,
+        opponent: &str,
+        competition: &str,
+        hour: u32,
+        minute: u32,
+    ) -> ValidatedFixture {
+        let sport = if team == "South Africa" {
+            Sport::RugbyUnion
+        } else {
+            Sport::Football
+        };
+        fixture()
+            .team(team)
+            .opponent(opponent)
+            .competition(competition)
+            .at(utc(2025, 9, 27, hour, minute))
+            .venue("Somewhere")
+            .sport(sport)
+            .validated()
+    }
+
+    #[test]
+    fn test_overlap_and_back_to_back() {
+        let fixtures = vec![
+            validated("Arsenal", "Newcastle", "Premier League", 16, 30),
+            validated("South Africa", "Argentina", "Rugby Championship", 15, 10),
+            validated("Arsenal", "Brighton", "Carabao Cup", 17, 15),
+        ];
+        let planner = WatchPartyPlanner::new(chrono_tz::Africa::Johannesburg).with_priorities(
+            WatchPriorities::from_json(r#"{ "teams": ["South Africa", "Arsenal"] }"#).unwrap(),
+        );
+
+        let conflicts = planner.find_conflicts(&fixtures);
+        assert_eq!(conflicts.len(), 2);
+
+        let conflict = &conflicts[0];
+        assert_eq!(conflict.kind, ConflictKind::Overlap);
+        assert_eq!(conflict.hosted().team, "South Africa");
+        assert_eq!(conflict.skipped().opponent, "Newcastle");
+        assert_eq!(
+            conflict.describe(planner.timezone),
+            "South Africa vs Argentina (Sat 27 Sep 17:10) overlaps Arsenal vs Newcastle (Sat 27 Sep 18:30); host South Africa vs Argentina (team priority #1 vs #2)"
+        );
+
+        // The Carabao Cup tie kicks off shortly after the rugby ends
+        assert_eq!(conflicts[1].kind, ConflictKind::BackToBack);
+        assert_eq!(conflicts[1].first.opponent, "Argentina");
+        assert_eq!(conflicts[1].second.opponent, "Brighton");
+
+        let no_buffer = planner.with_buffer(Duration::zero());
+        assert_eq!(no_buffer.find_conflicts(&fixtures).len(), 1);
+    }
+
+    #[test]
+    fn test_competition_priority_and_tie_break() {
+        let fixtures = vec![
+            validated("Arsenal Women", "Chelsea", "Women's Super League", 14, 0),
+            validated("Arsenal", "Liverpool", "Champions League", 14, 30),
+        ];
+
+        let planner = WatchPartyPlanner::new(chrono_tz::Europe::London);
+        let conflicts = planner.find_conflicts(&fixtures);
+        assert_eq!(conflicts[0].host, Host::First);
+        assert_eq!(conflicts[0].reason, "equal priority; earlier kick-off");
+
+        let planner = planner.with_priorities(WatchPriorities {
+            teams: Vec::new(),
+            competitions: vec!["champions league".to_string()],
+        });
+        let conflicts = planner.find_conflicts(&fixtures);
+        assert_eq!(conflicts[0].hosted().opponent, "Liverpool");
+        assert_eq!(conflicts[0].reason, "competition priority unlisted vs #1");
+    }
+
+    #[test]
+    fn test_unusable_and_same_team_ignored() {
+        let mut historical = validated("South Africa", "Argentina", "Rugby Championship", 15, 0);
+        historical.validation = crate::validation::FixtureValidation::Historical(now());
+        let fixtures = vec![
+            validated("Arsenal", "Newcastle", "Premier League", 16, 30),
+            validated("Arsenal", "Brighton", "Premier League", 17, 0),
+            historical,
+        ];
+
+        let planner = WatchPartyPlanner::new(chrono_tz::Europe::London);
+        assert!(planner.find_conflicts(&fixtures).is_empty());
+    }
+}
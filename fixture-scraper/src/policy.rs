@@ -0,0 +1,242 @@
+//! # Validation Policy
+//!
+//! The standard rules bake in our own expectations: fixtures from this year to
+//! two years ahead, kick-offs during UK waking hours, a missing venue as an
+//! error. A [`ValidationPolicy`] loaded from a config file changes those
+//! expectations without code:
+//!
+//! - `date_range`: how many years behind and ahead fixtures may fall
+//! - `time_windows`: reasonable start hours per sport and/or competition, in
+//!   the timezone that matters to whoever is watching
+//! - `confidence`: parse confidence thresholds
+//! - `severity_overrides`: a fixed severity per [`IssueCategory`]
+//! - `suppressions`: issue categories to ignore for a given team
+//!
+//! ```json
+//! {
+//!   "date_range": { "years_behind": 0, "years_ahead": 1 },
+//!   "time_windows": [
+//!     { "sport": "RugbyUnion", "timezone": "Africa/Johannesburg",
+//!       "start_hour": 6, "end_hour": 22 }
+//!   ],
+//!   "severity_overrides": { "MissingData": "Warning" },
+//!   "suppressions": [ { "team": "Arsenal Women", "categories": ["SuspiciousTime"] } ]
+//! }
+//! ```
+//!
+//! Every section is optional; an empty policy behaves like `RuleSet::standard()`.
+
+use crate::confidence::ConfidenceThresholds;
+use crate::rules::{
+    ConfidenceRule, DateRangeRule, KickOffTimeRule, KickOffWindowRule, RuleSet, Suppression,
+};
+use crate::sport::Sport;
+use crate::validation::{IssueCategory, IssueSeverity};
+use crate::ScrapeError;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Validation expectations loaded from config.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ValidationPolicy {
+    pub date_range: DateRangeRule,
+    /// Checked in order; the first window matching a fixture applies
+    pub time_windows: Vec<TimeWindow>,
+    pub confidence: ConfidenceThresholds,
+    pub severity_overrides: BTreeMap<IssueCategory, IssueSeverity>,
+    pub suppressions: Vec<Suppression>,
+}
+
+/// Reasonable start hours for one sport and/or competition.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimeWindow {
+    #[serde(default)]
+    pub sport: Option<Sport>,
+    /// Competition keyword, as for [`KickOffWindowRule`]
+    #[serde(default)]
+    pub competition: Option<String>,
+    /// IANA timezone name, e.g. "Africa/Johannesburg"
+    pub timezone: String,
+    /// First acceptable start hour (0-23)
+    pub start_hour: u32,
+    /// Last acceptable start hour (0-23), inclusive
+    pub end_hour: u32,
+}
+
+impl TimeWindow {
+    pub fn to_rule(&self) -> Result<KickOffWindowRule, ScrapeError> {
+        let timezone: Tz = self.timezone.parse().map_err(|_| {
+            ScrapeError::Parse(format!(
+                "Unknown timezone in time window: {}",
+                self.timezone
+            ))
+        })?;
+        let hours = KickOffWindowRule::hours(self.start_hour, self.end_hour)?;
+
+        let mut rule = KickOffWindowRule::new(timezone, hours);
+        rule.sport = self.sport;
+        rule.competition = self.competition.clone();
+        Ok(rule)
+    }
+}
+
+impl ValidationPolicy {
+    pub fn from_json(json: &str) -> Result<Self, ScrapeError> {
+        serde_json::from_str(json)
+            .map_err(|e| ScrapeError::Parse(format!("Invalid validation policy: {e}")))
+    }
+
+    /// Read a policy from a JSON file.
+    pub fn load(path: &Path) -> Result<Self, ScrapeError> {
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            ScrapeError::Parse(format!("Cannot read policy {}: {e}", path.display()))
+        })?;
+        Self::from_json(&json)
+    }
+
+    /// The standard rules, configured by this policy.
+    pub fn rule_set(&self) -> Result<RuleSet, ScrapeError> {
+        let windows = self
+            .time_windows
+            .iter()
+            .map(TimeWindow::to_rule)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut rule_set = RuleSet::standard()
+            .with_rule(self.date_range)
+            .with_rule(KickOffTimeRule::with_windows(windows))
+            .with_rule(ConfidenceRule::new(self.confidence));
+
+        for (category, severity) in &self.severity_overrides {
+            rule_set = rule_set.with_severity_override(*category, *severity);
+        }
+        for suppression in &self.suppressions {
+            rule_set = rule_set.with_suppression(suppression.clone());
+        }
+
+        Ok(rule_set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{clock, fixture, utc};
+    use crate::validation::FixtureValidation;
+    use crate::Fixture;
+
+    fn rugby_fixture(hour: u32) -> Fixture {
+        fixture()
+            .team("South Africa")
+            .opponent("New Zealand")
+            .at(utc(2025, 9, 6, hour, 0))
+            .venue("Eden Park")
+            .competition("Rugby Championship")
+            .sport(Sport::RugbyUnion)
+            .build()
+    }
+
+    fn issues(validation: FixtureValidation) -> Vec<crate::validation::ValidationIssue> {
+        match validation {
+            FixtureValidation::ValidWithWarnings(issues) | FixtureValidation::Invalid(issues) => {
+                issues
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_empty_policy_matches_standard_rules() {
+        let policy = ValidationPolicy::from_json("{}").unwrap();
+        assert_eq!(policy, ValidationPolicy::default());
+
+        let fixture = rugby_fixture(3); // 04:00 BST
+        assert_eq!(
+            policy.rule_set().unwrap().validate(&fixture, &clock()),
+            RuleSet::standard().validate(&fixture, &clock())
+        );
+    }
+
+    #[test]
+    fn test_time_window_in_local_timezone() {
+        let policy = ValidationPolicy::from_json(
+            r#"{ "time_windows": [
+                { "sport": "RugbyUnion", "timezone": "Africa/Johannesburg",
+                  "start_hour": 5, "end_hour": 22 }
+            ] }"#,
+        )
+        .unwrap();
+        let rules = policy.rule_set().unwrap();
+
+        // 03:00 UTC is 04:00 in London but 05:00 in Johannesburg
+        assert!(!issues(RuleSet::standard().validate(&rugby_fixture(3), &clock())).is_empty());
+        assert_eq!(
+            rules.validate(&rugby_fixture(3), &clock()),
+            FixtureValidation::Valid
+        );
+
+        // 22:00 UTC is 23:00 in London but midnight in Johannesburg
+        assert!(issues(RuleSet::standard().validate(&rugby_fixture(22), &clock())).is_empty());
+        let flagged = issues(rules.validate(&rugby_fixture(22), &clock()));
+        assert_eq!(flagged[0].category, IssueCategory::SuspiciousTime);
+        assert!(flagged[0].message.contains("Africa/Johannesburg"));
+    }
+
+    #[test]
+    fn test_severity_overrides_and_suppressions() {
+        let mut fixture = rugby_fixture(14);
+        fixture.venue = "Unknown".to_string();
+
+        let policy = ValidationPolicy::from_json(
+            r#"{ "severity_overrides": { "MissingData": "Critical" } }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            policy.rule_set().unwrap().validate(&fixture, &clock()),
+            FixtureValidation::Invalid(_)
+        ));
+
+        let policy = ValidationPolicy::from_json(
+            r#"{ "severity_overrides": { "MissingData": "Critical" },
+                 "suppressions": [ { "team": "new zealand", "categories": ["MissingData"] } ] }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            policy.rule_set().unwrap().validate(&fixture, &clock()),
+            FixtureValidation::Valid
+        );
+    }
+
+    #[test]
+    fn test_date_range_bounds() {
+        let policy =
+            ValidationPolicy::from_json(r#"{ "date_range": { "years_ahead": 0 } }"#).unwrap();
+        let mut fixture = rugby_fixture(14);
+        fixture.datetime = utc(2026, 2, 7, 14, 0);
+
+        assert!(matches!(
+            policy.rule_set().unwrap().validate(&fixture, &clock()),
+            FixtureValidation::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_time_window() {
+        for (start_hour, end_hour) in [(8, 24), (22, 6)] {
+            let policy = ValidationPolicy {
+                time_windows: vec![TimeWindow {
+                    sport: None,
+                    competition: None,
+                    timezone: "Europe/London".to_string(),
+                    start_hour,
+                    end_hour,
+                }],
+                ..Default::default()
+            };
+            assert!(policy.rule_set().is_err());
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! # Reminder Policy
+//!
+//! A derby deserves a reminder the day before; a pre-season friendly an hour
+//! before is plenty. A [`ReminderPolicy`] loaded from config decides which
+//! reminders each fixture gets. Calendar exports emit them as `VALARM`
+//! components, and [`ReminderPolicy::due`] drives in-process notifications
+//! from the same rules.
+//!
+//! ```json
+//! {
+//!   "importance": [
+//!     { "team": "Arsenal", "opponent": "Tottenham Hotspur", "importance": "High" },
+//!     { "competition": "Friendly", "importance": "Low" }
+//!   ],
+//!   "rules": [
+//!     { "importance": "High", "reminders": [
+//!       { "minutes_before": 1440, "description": "{title} tomorrow at {kick_off}" },
+//!       { "minutes_before": 60 }
+//!     ] },
+//!     { "importance": "Low", "reminders": [ { "minutes_before": 60 } ] }
+//!   ],
+//!   "default": [ { "minutes_before": 120 } ]
+//! }
+//! ```
+//!
+//! Importance and reminder rules are both checked in order, and the first
+//! match applies. A `competition` matches any competition containing it
+//! ("Friendly" covers "Pre-season Friendly"). Fixtures that were called off
+//! get no reminders.
+//!
+//! ## Description templates
+//!
+//! Descriptions use the [`Template`] syntax of event templates, with the
+//! variables in [`VARIABLES`]: `{title}`, `{team}`, `{opponent}`,
+//! `{competition}`, `{venue}`, `{kick_off}` (local time) and `{time_until}`
+//! ("1 day", "2 hours", "30 minutes"). Unknown variables are rejected when
+//! the policy is loaded.
+
+use crate::dedupe::normalise_team;
+use crate::templates::Template;
+use crate::{Fixture, ScrapeError};
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Description used when a reminder doesn't set one.
+pub const DEFAULT_DESCRIPTION: &str = "{title} starts in {time_until}";
+
+/// Every variable a reminder description may use; see the module docs.
+pub const VARIABLES: [&str; 7] = [
+    "title",
+    "team",
+    "opponent",
+    "competition",
+    "venue",
+    "kick_off",
+    "time_until",
+];
+
+/// How much a fixture matters to the group.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Importance {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// Fixtures given an importance; unset fields match anything.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImportanceRule {
+    /// Team as displayed, e.g. "Arsenal Women"
+    #[serde(default)]
+    pub team: Option<String>,
+    #[serde(default)]
+    pub opponent: Option<String>,
+    #[serde(default)]
+    pub competition: Option<String>,
+    pub importance: Importance,
+}
+
+/// One reminder before kick-off.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "ReminderConfig", into = "ReminderConfig")]
+pub struct Reminder {
+    pub minutes_before: u32,
+    /// [`DEFAULT_DESCRIPTION`] when unset
+    pub description: Option<Template>,
+}
+
+/// The on-disk form of a [`Reminder`], with the description unparsed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReminderConfig {
+    minutes_before: u32,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+/// Reminders for fixtures matching every field that is set.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReminderRule {
+    #[serde(default)]
+    pub team: Option<String>,
+    #[serde(default)]
+    pub competition: Option<String>,
+    #[serde(default)]
+    pub importance: Option<Importance>,
+    pub reminders: Vec<Reminder>,
+}
+
+/// Which reminders each fixture gets.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ReminderPolicy {
+    pub importance: Vec<ImportanceRule>,
+    pub rules: Vec<ReminderRule>,
+    /// For fixtures no rule matches
+    pub default: Vec<Reminder>,
+}
+
+/// A reminder whose time has come, for in-process notifications.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DueReminder<'a> {
+    pub fixture: &'a Fixture,
+    pub at: DateTime<Utc>,
+    pub message: String,
+}
+
+impl Reminder {
+    pub fn new(minutes_before: u32) -> Self {
+        Self {
+            minutes_before,
+            description: None,
+        }
+    }
+
+    /// Use `template` as the description; it may only use [`VARIABLES`].
+    pub fn with_description(mut self, template: &str) -> Result<Self, ScrapeError> {
+        self.description = Some(Template::parse_with_variables(template, &VARIABLES)?);
+        Ok(self)
+    }
+
+    /// When the reminder fires for `fixture`.
+    pub fn trigger_at(&self, fixture: &Fixture) -> DateTime<Utc> {
+        fixture.datetime - Duration::minutes(i64::from(self.minutes_before))
+    }
+
+    /// The description template filled in for `fixture`, with times in `timezone`.
+    pub fn message(&self, fixture: &Fixture, timezone: Tz) -> String {
+        let kick_off = fixture
+            .datetime
+            .with_timezone(&timezone)
+            .format("%a %d %b %H:%M")
+            .to_string();
+        let variables = HashMap::from([
+            ("title", fixture.title()),
+            ("team", fixture.team_label()),
+            ("opponent", fixture.opponent.clone()),
+            ("competition", fixture.competition.clone()),
+            ("venue", fixture.venue.clone()),
+            ("kick_off", kick_off),
+            ("time_until", describe_minutes(self.minutes_before)),
+        ]);
+
+        match &self.description {
+            Some(template) => template.render(&variables),
+            None => Template::parse_with_variables(DEFAULT_DESCRIPTION, &VARIABLES)
+                .expect("default description parses")
+                .render(&variables),
+        }
+    }
+}
+
+impl TryFrom<ReminderConfig> for Reminder {
+    type Error = ScrapeError;
+
+    fn try_from(config: ReminderConfig) -> Result<Self, Self::Error> {
+        let reminder = Self::new(config.minutes_before);
+        match config.description {
+            Some(description) => reminder.with_description(&description),
+            None => Ok(reminder),
+        }
+    }
+}
+
+impl From<Reminder> for ReminderConfig {
+    fn from(reminder: Reminder) -> Self {
+        Self {
+            minutes_before: reminder.minutes_before,
+            description: reminder
+                .description
+                .map(|template| template.source().to_string()),
+        }
+    }
+}
+
+impl ImportanceRule {
+    fn matches(&self, fixture: &Fixture) -> bool {
+        matches_team(&self.team, &fixture.team_label())
+            && matches_team(&self.opponent, &fixture.opponent)
+            && matches_competition(&self.competition, fixture)
+    }
+}
+
+impl ReminderPolicy {
+    pub fn from_json(json: &str) -> Result<Self, ScrapeError> {
+        serde_json::from_str(json)
+            .map_err(|e| ScrapeError::Parse(format!("Invalid reminder policy: {e}")))
+    }
+
+    /// Read a policy from a JSON file.
+    pub fn load(path: &Path) -> Result<Self, ScrapeError> {
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            ScrapeError::Parse(format!("Cannot read reminders {}: {e}", path.display()))
+        })?;
+        Self::from_json(&json)
+    }
+
+    /// Importance from the first matching rule, `Normal` otherwise.
+    pub fn importance(&self, fixture: &Fixture) -> Importance {
+        self.importance
+            .iter()
+            .find(|rule| rule.matches(fixture))
+            .map(|rule| rule.importance)
+            .unwrap_or_default()
+    }
+
+    /// Reminders from the first matching rule, or the defaults; none for called-off fixtures.
+    pub fn reminders_for(&self, fixture: &Fixture) -> &[Reminder] {
+        if !fixture.status.is_active() {
+            return &[];
+        }
+
+        let importance = self.importance(fixture);
+        self.rules
+            .iter()
+            .find(|rule| {
+                matches_team(&rule.team, &fixture.team_label())
+                    && matches_competition(&rule.competition, fixture)
+                    && rule.importance.is_none_or(|wanted| wanted == importance)
+            })
+            .map_or(&self.default, |rule| &rule.reminders)
+    }
+
+    /// Reminders firing at or after `from` and before `to`, earliest first.
+    pub fn due<'a>(
+        &self,
+        fixtures: &'a [Fixture],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        timezone: Tz,
+    ) -> Vec<DueReminder<'a>> {
+        let mut due: Vec<DueReminder<'a>> = fixtures
+            .iter()
+            .flat_map(|fixture| {
+                self.reminders_for(fixture)
+                    .iter()
+                    .map(move |reminder| (fixture, reminder))
+            })
+            .filter_map(|(fixture, reminder)| {
+                let at = reminder.trigger_at(fixture);
+                (from <= at && at < to).then(|| DueReminder {
+                    fixture,
+                    at,
+                    message: reminder.message(fixture, timezone),
+                })
+            })
+            .collect();
+        due.sort_by_key(|reminder| reminder.at);
+        due
+    }
+}
+
+fn matches_team(wanted: &Option<String>, actual: &str) -> bool {
+    wanted
+        .as_ref()
+        .is_none_or(|team| normalise_team(team) == normalise_team(actual))
+}
+
+fn matches_competition(wanted: &Option<String>, fixture: &Fixture) -> bool {
+    wanted
+        .as_ref()
+        .is_none_or(|competition| fixture.competition_matches(competition))
+}
+
+/// "1 day", "2 hours", "1 hour 30 minutes"
+fn describe_minutes(minutes: u32) -> String {
+    let plural = |n: u32, unit: &str| {
+        if n == 1 {
+            format!("1 {unit}")
+        } else {
+            format!("{n} {unit}s")
+        }
+    };
+    let (days, hours, minutes) = (minutes / 1440, minutes % 1440 / 60, minutes % 60);
+
+    let parts: Vec<String> = [(days, "day"), (hours, "hour"), (minutes, "minute")]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| plural(n, unit))
+        .collect();
+    if parts.is_empty() {
+        "0 minutes".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status::FixtureStatus;
+    use crate::test_support::{self, utc};
+
+    fn fixture(opponent: &str, competition: &str) -> Fixture {
+        test_support::fixture()
+            .opponent(opponent)
+            .competition(competition)
+            .at(utc(2025, 11, 22, 17, 30))
+            .build()
+    }
+
+    fn policy() -> ReminderPolicy {
+        ReminderPolicy::from_json(
+            r#"{
+                "importance": [
+                    { "team": "Arsenal", "opponent": "Tottenham Hotspur", "importance": "High" },
+                    { "competition": "friendly", "importance": "Low" }
+                ],
+                "rules": [
+                    { "importance": "High", "reminders": [
+                        { "minutes_before": 1440, "description": "{title} tomorrow at {kick_off}" },
+                        { "minutes_before": 60 }
+                    ] },
+                    { "importance": "Low", "reminders": [ { "minutes_before": 60 } ] }
+                ],
+                "default": [ { "minutes_before": 120 } ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_derby_friendly_and_default() {
+        let policy = policy();
+        let derby = fixture("Tottenham Hotspur", "Premier League");
+        let friendly = fixture("Villarreal", "Pre-season Friendly");
+        let league = fixture("Fulham", "Premier League");
+
+        assert_eq!(policy.importance(&derby), Importance::High);
+        assert_eq!(policy.importance(&friendly), Importance::Low);
+        assert_eq!(policy.importance(&league), Importance::Normal);
+
+        assert_eq!(policy.reminders_for(&derby).len(), 2);
+        assert_eq!(policy.reminders_for(&friendly), &[Reminder::new(60)]);
+        assert_eq!(policy.reminders_for(&league), &[Reminder::new(120)]);
+
+        let postponed = derby.with_status(FixtureStatus::Postponed);
+        assert!(policy.reminders_for(&postponed).is_empty());
+    }
+
+    #[test]
+    fn test_message_template() {
+        let derby = fixture("Tottenham Hotspur", "Premier League");
+        let reminders = policy();
+        let reminders = reminders.reminders_for(&derby);
+
+        assert_eq!(
+            reminders[0].message(&derby, chrono_tz::Europe::London),
+            "Arsenal vs Tottenham Hotspur tomorrow at Sat 22 Nov 17:30"
+        );
+        assert_eq!(
+            reminders[1].message(&derby, chrono_tz::Europe::London),
+            "Arsenal vs Tottenham Hotspur starts in 1 hour"
+        );
+        assert_eq!(describe_minutes(90), "1 hour 30 minutes");
+        assert_eq!(describe_minutes(2880), "2 days");
+    }
+
+    #[test]
+    fn test_description_typos_are_rejected() {
+        let typo = r#"{ "default": [ { "minutes_before": 60, "description": "{tittle} soon" } ] }"#;
+        assert!(ReminderPolicy::from_json(typo)
+            .unwrap_err()
+            .to_string()
+            .contains("{tittle}"));
+        // Event-only variables aren't available to reminders
+        assert!(Reminder::new(60).with_description("{issues}").is_err());
+
+        let reminder = Reminder::new(30)
+            .with_description("{?venue}At {venue} in {/venue}{time_until}")
+            .unwrap();
+        let json = serde_json::to_string(&reminder).unwrap();
+        assert_eq!(serde_json::from_str::<Reminder>(&json).unwrap(), reminder);
+        assert_eq!(
+            reminder.message(
+                &fixture("Fulham", "Premier League"),
+                chrono_tz::Europe::London
+            ),
+            "At Emirates Stadium in 30 minutes"
+        );
+    }
+
+    #[test]
+    fn test_due_reminders() {
+        let fixtures = vec![
+            fixture("Tottenham Hotspur", "Premier League"),
+            fixture("Villarreal", "Pre-season Friendly"),
+        ];
+        let policy = policy();
+
+        // The hour before kick-off: both "1 hour" reminders fire
+        let from = utc(2025, 11, 22, 16, 0);
+        let due = policy.due(
+            &fixtures,
+            from,
+            from + Duration::hours(1),
+            chrono_tz::Europe::London,
+        );
+        assert_eq!(due.len(), 2);
+        assert!(due
+            .iter()
+            .all(|reminder| reminder.at == fixtures[0].datetime - Duration::hours(1)));
+
+        let day_before = from - Duration::days(1);
+        let due = policy.due(
+            &fixtures,
+            day_before,
+            day_before + Duration::hours(2),
+            chrono_tz::Europe::London,
+        );
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].fixture.opponent, "Tottenham Hotspur");
+    }
+}
@@ -0,0 +1,1476 @@
+//! # Pluggable Validation Rules
+//!
+//! Every check the validator makes is a [`ValidationRule`]: a named object that
+//! looks at one fixture (plus a clock, and optionally the rest of the batch) and
+//! reports issues. A [`RuleSet`] runs its rules in order and turns their issues
+//! into a [`FixtureValidation`].
+//!
+//! ## Building a rule set
+//!
+//! - [`RuleSet::standard`] holds the built-in checks `FixtureValidator` uses
+//! - `with_rule` adds a rule, replacing any existing rule with the same name,
+//!   so `with_rule(ConfidenceRule::new(strict))` swaps the thresholds
+//! - [`RuleSetConfig`] describes a rule set as JSON, including the two
+//!   configurable custom rules ([`KickOffWindowRule`], [`OpponentListRule`])
+//!
+//! Rules outside this crate only need to implement the trait.
+
+use crate::clock::Clock;
+use crate::confidence::{placeholder_fields, ConfidenceThresholds, FixtureField, ParseConfidence};
+use crate::dedupe::normalise_team;
+use crate::parsing::ParsingStrategy;
+use crate::sport::Sport;
+use crate::status::FixtureStatus;
+use crate::validation::{
+    FixtureValidation, IssueCategory, IssueSeverity, ValidatedFixture, ValidationIssue,
+};
+use crate::{Fixture, ScrapeError};
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// What a rule can see besides the fixture itself.
+#[derive(Clone, Copy)]
+pub struct ValidationContext<'a> {
+    pub clock: &'a dyn Clock,
+    /// Every fixture being validated together (including this one), when known
+    pub batch: Option<&'a [Fixture]>,
+}
+
+impl<'a> ValidationContext<'a> {
+    pub fn new(clock: &'a dyn Clock) -> Self {
+        Self { clock, batch: None }
+    }
+
+    pub fn with_batch(mut self, batch: &'a [Fixture]) -> Self {
+        self.batch = Some(batch);
+        self
+    }
+}
+
+/// A single validation check.
+pub trait ValidationRule: Send + Sync {
+    /// Unique name; a rule set holds at most one rule per name.
+    fn name(&self) -> &str;
+
+    fn check(&self, fixture: &Fixture, context: &ValidationContext<'_>) -> Vec<ValidationIssue>;
+}
+
+/// An ordered collection of rules, plus policy applied to the issues they raise.
+pub struct RuleSet {
+    rules: Vec<Box<dyn ValidationRule>>,
+    severity_overrides: BTreeMap<IssueCategory, IssueSeverity>,
+    suppressions: Vec<Suppression>,
+}
+
+impl RuleSet {
+    /// A rule set with no rules; every future fixture is `Valid`.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            severity_overrides: BTreeMap::new(),
+            suppressions: Vec::new(),
+        }
+    }
+
+    /// The built-in checks, in the order `FixtureValidator` has always run them.
+    pub fn standard() -> Self {
+        Self::new()
+            .with_rule(DateRangeRule::default())
+            .with_rule(WeekdayConsistencyRule)
+            .with_rule(KickOffTimeRule::default())
+            .with_rule(KickOffAgreementRule)
+            .with_rule(TimezoneResolutionRule)
+            .with_rule(FixtureDataRule)
+            .with_rule(FixtureStatusRule)
+            .with_rule(ScheduleClashRule::default())
+            .with_rule(ConfidenceRule::default())
+    }
+
+    /// Add a rule, replacing an existing rule with the same name in place.
+    pub fn with_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.push(Box::new(rule));
+        self
+    }
+
+    /// Drop the rule with this name, if present.
+    pub fn without_rule(mut self, name: &str) -> Self {
+        self.rules.retain(|rule| rule.name() != name);
+        self
+    }
+
+    /// Report every issue of `category` at `severity`, whichever rule raised it.
+    pub fn with_severity_override(
+        mut self,
+        category: IssueCategory,
+        severity: IssueSeverity,
+    ) -> Self {
+        self.severity_overrides.insert(category, severity);
+        self
+    }
+
+    /// Drop issues a suppression covers.
+    pub fn with_suppression(mut self, suppression: Suppression) -> Self {
+        self.suppressions.push(suppression);
+        self
+    }
+
+    /// Names of the rules, in running order.
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Build a rule set from its config description.
+    pub fn from_config(config: &RuleSetConfig) -> Result<Self, ScrapeError> {
+        let mut rule_set = if config.include_standard {
+            Self::standard()
+        } else {
+            Self::new()
+        };
+
+        for rule in &config.rules {
+            rule_set.push(rule.build()?);
+        }
+
+        Ok(rule_set)
+    }
+
+    /// Validate a fixture on its own.
+    pub fn validate(&self, fixture: &Fixture, clock: &dyn Clock) -> FixtureValidation {
+        self.validate_in_context(fixture, &ValidationContext::new(clock))
+    }
+
+    /// Validate a fixture; past fixtures are `Historical` without running any rule.
+    pub fn validate_in_context(
+        &self,
+        fixture: &Fixture,
+        context: &ValidationContext<'_>,
+    ) -> FixtureValidation {
+        let now = context.clock.now();
+        if fixture.datetime < now {
+            return FixtureValidation::Historical(now);
+        }
+
+        let issues = self
+            .rules
+            .iter()
+            .flat_map(|rule| rule.check(fixture, context))
+            .filter(|issue| {
+                !self
+                    .suppressions
+                    .iter()
+                    .any(|suppression| suppression.covers(fixture, issue))
+            })
+            .map(|mut issue| {
+                if let Some(severity) = self.severity_overrides.get(&issue.category) {
+                    issue.severity = *severity;
+                }
+                issue
+            })
+            .collect();
+
+        FixtureValidation::from_issues(issues)
+    }
+
+    /// Validate fixtures together, so batch-aware rules can compare them.
+    pub fn validate_all(&self, fixtures: Vec<Fixture>, clock: &dyn Clock) -> Vec<ValidatedFixture> {
+        let validations: Vec<FixtureValidation> = {
+            let context = ValidationContext::new(clock).with_batch(&fixtures);
+            fixtures
+                .iter()
+                .map(|fixture| self.validate_in_context(fixture, &context))
+                .collect()
+        };
+
+        fixtures
+            .into_iter()
+            .zip(validations)
+            .map(|(fixture, validation)| ValidatedFixture {
+                confidence: ParseConfidence::for_fixture(&fixture),
+                fixture,
+                validation,
+            })
+            .collect()
+    }
+
+    fn push(&mut self, rule: Box<dyn ValidationRule>) {
+        match self.rules.iter().position(|r| r.name() == rule.name()) {
+            Some(index) => self.rules[index] = rule,
+            None => self.rules.push(rule),
+        }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Silences some issue categories for fixtures involving one team.
+///
+/// Matches the followed team or the opponent, case-insensitively, so
+/// `{ "team": "Arsenal Women", "categories": ["MissingData"] }` hides the
+/// venue gaps that squad's listings always have.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Suppression {
+    pub team: String,
+    pub categories: Vec<IssueCategory>,
+}
+
+impl Suppression {
+    pub fn new(team: &str, categories: Vec<IssueCategory>) -> Self {
+        Self {
+            team: team.to_string(),
+            categories,
+        }
+    }
+
+    pub fn covers(&self, fixture: &Fixture, issue: &ValidationIssue) -> bool {
+        let involves_team = [&fixture.team, &fixture.opponent]
+            .iter()
+            .any(|name| name.trim().eq_ignore_ascii_case(self.team.trim()));
+
+        involves_team && self.categories.contains(&issue.category)
+    }
+}
+
+/// Fixtures must fall between the start of the year `years_behind` years ago
+/// and the end of the year `years_ahead` years on.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DateRangeRule {
+    pub years_behind: i32,
+    pub years_ahead: i32,
+}
+
+impl Default for DateRangeRule {
+    /// Current year start to 2 years in future
+    fn default() -> Self {
+        Self {
+            years_behind: 0,
+            years_ahead: 2,
+        }
+    }
+}
+
+impl ValidationRule for DateRangeRule {
+    fn name(&self) -> &str {
+        "date_range"
+    }
+
+    fn check(&self, fixture: &Fixture, context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        let current_year = context.clock.now().year();
+        let fixture_year = fixture.datetime.year();
+
+        let min_year = current_year - self.years_behind;
+        let max_year = current_year + self.years_ahead;
+
+        if fixture_year < min_year || fixture_year > max_year {
+            vec![ValidationIssue {
+                severity: IssueSeverity::Critical, // Critical = thrown out entirely
+                category: IssueCategory::DataInconsistency,
+                message: format!(
+                    "Fixture date {} is outside reasonable range ({}-{}). Fixtures only planned {} years ahead.",
+                    fixture.datetime.format("%Y-%m-%d"),
+                    min_year,
+                    max_year,
+                    self.years_ahead
+                ),
+                suggested_fix: Some("Check date parsing and source data accuracy".to_string()),
+            }]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// The weekday the source claimed must match the date (the sophisticated part!).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WeekdayConsistencyRule;
+
+impl WeekdayConsistencyRule {
+    fn extract_expected_weekday(fixture: &Fixture) -> Option<Weekday> {
+        // Use rich ParseMetadata instead of primitive string parsing
+        let weekday_mismatch = fixture.parse_metadata.weekday_mismatch.as_ref()?;
+
+        // Localised sources name the weekday in their own language ("Samedi")
+        if let Some(locale) = fixture.parse_metadata.locale {
+            if let Some(weekday) = locale.weekday_from_name(&weekday_mismatch.claimed_weekday) {
+                return Some(weekday);
+            }
+        }
+
+        // Extract weekday from the claimed weekday in the mismatch data
+        let claimed = weekday_mismatch.claimed_weekday.to_lowercase();
+        [
+            ("sun", Weekday::Sun),
+            ("mon", Weekday::Mon),
+            ("tue", Weekday::Tue),
+            ("wed", Weekday::Wed),
+            ("thu", Weekday::Thu),
+            ("fri", Weekday::Fri),
+            ("sat", Weekday::Sat),
+        ]
+        .into_iter()
+        .find(|(prefix, _)| claimed.contains(prefix))
+        .map(|(_, weekday)| weekday)
+    }
+}
+
+impl ValidationRule for WeekdayConsistencyRule {
+    fn name(&self) -> &str {
+        "weekday_consistency"
+    }
+
+    fn check(&self, fixture: &Fixture, _context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        let date = fixture.to_london_time().date_naive();
+        let actual_weekday = date.weekday();
+
+        match Self::extract_expected_weekday(fixture) {
+            Some(expected_weekday) if expected_weekday != actual_weekday => vec![ValidationIssue {
+                severity: IssueSeverity::Warning,
+                category: IssueCategory::DateWeekdayMismatch,
+                message: format!(
+                    "Date {} is a {}, but source indicated {}. Source may have incorrect weekday.",
+                    date.format("%b %d, %Y"),
+                    weekday_name(actual_weekday),
+                    weekday_name(expected_weekday)
+                ),
+                suggested_fix: Some(
+                    "Verify fixture date. If date is correct, ignore weekday discrepancy."
+                        .to_string(),
+                ),
+            }],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Start times outside the sport's usual hours (London time) are suspicious.
+///
+/// `windows` override those hours: the first window that applies to a fixture
+/// decides instead.
+#[derive(Debug, Clone, Default)]
+pub struct KickOffTimeRule {
+    pub windows: Vec<KickOffWindowRule>,
+}
+
+impl KickOffTimeRule {
+    pub fn with_windows(windows: Vec<KickOffWindowRule>) -> Self {
+        Self { windows }
+    }
+}
+
+impl ValidationRule for KickOffTimeRule {
+    fn name(&self) -> &str {
+        "kick_off_time"
+    }
+
+    fn check(&self, fixture: &Fixture, context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        if let Some(window) = self.windows.iter().find(|w| w.applies_to(fixture)) {
+            return window.check(fixture, context);
+        }
+
+        let london_time = fixture.to_london_time();
+        let hour = london_time.hour();
+
+        // Each sport has its own idea of "unusual"
+        let Some(reasonable_hours) = fixture.sport.reasonable_start_hours() else {
+            return Vec::new();
+        };
+        if reasonable_hours.contains(&hour) {
+            return Vec::new();
+        }
+
+        vec![ValidationIssue {
+            severity: IssueSeverity::Warning,
+            category: IssueCategory::SuspiciousTime,
+            message: format!(
+                "Unusual {} start time: {}:{:02} London time",
+                fixture.sport.to_string().to_lowercase(),
+                hour,
+                london_time.minute()
+            ),
+            suggested_fix: Some("Verify time zone conversion is correct".to_string()),
+        }]
+    }
+}
+
+/// The page's own kick-off readings (attribute, visible text, header) must agree.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KickOffAgreementRule;
+
+impl ValidationRule for KickOffAgreementRule {
+    fn name(&self) -> &str {
+        "kick_off_agreement"
+    }
+
+    fn check(&self, fixture: &Fixture, _context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        // Textual readings carry no year, so compare month, day and time in London
+        let london_key = |datetime: &DateTime<Utc>| {
+            let london = datetime.with_timezone(&chrono_tz::Europe::London);
+            (london.month(), london.day(), london.hour(), london.minute())
+        };
+        let describe = |datetime: &DateTime<Utc>| {
+            datetime
+                .with_timezone(&chrono_tz::Europe::London)
+                .format("%a %d %b %H:%M")
+                .to_string()
+        };
+
+        let mut readings = fixture
+            .parse_metadata
+            .kick_off_readings
+            .iter()
+            .filter_map(|reading| reading.parsed.map(|parsed| (reading.source, parsed)));
+        let Some((primary_source, primary)) = readings.next() else {
+            return Vec::new();
+        };
+
+        let disagreements: Vec<String> = readings
+            .filter(|(_, parsed)| london_key(parsed) != london_key(&primary))
+            .map(|(source, parsed)| format!("{source} says {}", describe(&parsed)))
+            .collect();
+
+        if disagreements.is_empty() {
+            return Vec::new();
+        }
+
+        vec![ValidationIssue {
+            severity: IssueSeverity::Error,
+            category: IssueCategory::SourceDisagreement,
+            message: format!(
+                "Kick-off sources disagree: {primary_source} says {}, {} (London time)",
+                describe(&primary),
+                disagreements.join(", ")
+            ),
+            suggested_fix: Some(
+                "Check the club's fixture page or an official broadcaster listing".to_string(),
+            ),
+        }]
+    }
+}
+
+/// Kick-offs that needed a clock-change decision or the fallback timezone.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimezoneResolutionRule;
+
+impl ValidationRule for TimezoneResolutionRule {
+    fn name(&self) -> &str {
+        "timezone_resolution"
+    }
+
+    fn check(&self, fixture: &Fixture, _context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let metadata = &fixture.parse_metadata;
+
+        if let Some(resolution) = &metadata.dst_resolution {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Warning,
+                category: IssueCategory::TimezoneResolution,
+                message: resolution.describe(),
+                suggested_fix: Some(
+                    "Confirm the kick-off time around the clock change with the official source"
+                        .to_string(),
+                ),
+            });
+        }
+
+        if let ParsingStrategy::TimezoneFallback(timezone) = &metadata.parsing_strategy {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Warning,
+                category: IssueCategory::TimezoneResolution,
+                message: format!("Kick-off time was interpreted in fallback timezone {timezone}"),
+                suggested_fix: Some(
+                    "Kick-off may be off by an hour; verify against the official source"
+                        .to_string(),
+                ),
+            });
+        }
+
+        issues
+    }
+}
+
+/// Placeholder opponents and venues, and sport details that contradict the sport.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixtureDataRule;
+
+impl ValidationRule for FixtureDataRule {
+    fn name(&self) -> &str {
+        "fixture_data"
+    }
+
+    fn check(&self, fixture: &Fixture, _context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        // Check for placeholder/missing data
+        if fixture.opponent.contains("TBD") || fixture.opponent.contains("Unknown") {
+            let message = if fixture.sport.is_head_to_head() {
+                "Opponent not yet determined"
+            } else {
+                "Event name not yet determined"
+            };
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Warning,
+                category: IssueCategory::MissingData,
+                message: message.to_string(),
+                suggested_fix: Some("Check source closer to fixture date".to_string()),
+            });
+        }
+
+        // Sport-specific details must describe the same sport as the fixture
+        if let Some(details) = &fixture.sport_details {
+            if details.sport() != fixture.sport {
+                issues.push(ValidationIssue {
+                    severity: IssueSeverity::Error,
+                    category: IssueCategory::DataInconsistency,
+                    message: format!(
+                        "Fixture is marked as {} but carries {} details",
+                        fixture.sport,
+                        details.sport()
+                    ),
+                    suggested_fix: Some(
+                        "Set sport details with Fixture::with_sport_details".to_string(),
+                    ),
+                });
+            }
+        }
+
+        if fixture.venue.contains("Unknown") || fixture.venue.is_empty() {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Error,
+                category: IssueCategory::MissingData,
+                message: "Venue information missing".to_string(),
+                suggested_fix: None,
+            });
+        }
+
+        issues
+    }
+}
+
+/// Postponed, cancelled, abandoned and rescheduled fixtures get a note.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixtureStatusRule;
+
+impl ValidationRule for FixtureStatusRule {
+    fn name(&self) -> &str {
+        "fixture_status"
+    }
+
+    fn check(&self, fixture: &Fixture, _context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        let (message, suggested_fix) = match fixture.status {
+            FixtureStatus::Scheduled | FixtureStatus::Completed => return Vec::new(),
+            FixtureStatus::Postponed => (
+                "Fixture postponed; new date to be confirmed".to_string(),
+                "Watch the source for a new date",
+            ),
+            FixtureStatus::Cancelled => (
+                "Fixture cancelled".to_string(),
+                "Let the group know the watch party is off",
+            ),
+            FixtureStatus::Abandoned => (
+                "Fixture abandoned".to_string(),
+                "Watch the source for a replay date",
+            ),
+            FixtureStatus::Rescheduled { previous } => (
+                format!(
+                    "Kick-off moved from {} (London time)",
+                    previous
+                        .with_timezone(&chrono_tz::Europe::London)
+                        .format("%a %d %b %H:%M")
+                ),
+                "Update any watch-party plans for the new time",
+            ),
+        };
+
+        vec![ValidationIssue {
+            severity: IssueSeverity::Warning,
+            category: IssueCategory::StatusChange,
+            message,
+            suggested_fix: Some(suggested_fix.to_string()),
+        }]
+    }
+}
+
+/// Parse confidence below the thresholds becomes an issue of matching severity.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConfidenceRule {
+    pub thresholds: ConfidenceThresholds,
+}
+
+impl ConfidenceRule {
+    pub fn new(thresholds: ConfidenceThresholds) -> Self {
+        Self { thresholds }
+    }
+}
+
+impl ValidationRule for ConfidenceRule {
+    fn name(&self) -> &str {
+        "confidence"
+    }
+
+    fn check(&self, fixture: &Fixture, _context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        let confidence = ParseConfidence::for_fixture(fixture);
+        let Some(severity) = self.thresholds.severity_for(confidence.score) else {
+            return Vec::new();
+        };
+
+        vec![ValidationIssue {
+            severity,
+            category: IssueCategory::LowConfidence,
+            message: format!("Low parse confidence: {confidence}"),
+            suggested_fix: Some("Cross-check the fixture against another source".to_string()),
+        }]
+    }
+}
+
+/// Where a venue is, for travel-time checks between fixtures.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct VenueLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl VenueLocation {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Great-circle distance in kilometres.
+    pub fn distance_km(&self, other: &VenueLocation) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let half_dlat = (lat2 - lat1) / 2.0;
+        let half_dlon = (other.longitude - self.longitude).to_radians() / 2.0;
+        let a = half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlon.sin().powi(2);
+        6371.0 * 2.0 * a.sqrt().asin()
+    }
+}
+
+/// Fixtures of the same team that overlap or follow each other implausibly fast.
+///
+/// Only sees clashes when the batch is available (`RuleSet::validate_all`).
+/// Each fixture's full time window (including possible extra time) counts, and
+/// when both venues have known locations the gap must also cover the journey.
+/// Non-head-to-head sports are skipped: a motorsport weekend has several
+/// sessions a day.
+#[derive(Debug, Clone)]
+pub struct ScheduleClashRule {
+    /// Least time between the latest possible end of one fixture and the next kick-off
+    pub min_turnaround: Duration,
+    /// Venue locations, keyed by venue name as scraped
+    pub venues: BTreeMap<String, VenueLocation>,
+    pub travel_speed_kmh: f64,
+}
+
+impl Default for ScheduleClashRule {
+    /// A day between fixtures, travelling at roughly airliner speed
+    fn default() -> Self {
+        Self {
+            min_turnaround: Duration::hours(24),
+            venues: BTreeMap::new(),
+            travel_speed_kmh: 500.0,
+        }
+    }
+}
+
+impl ScheduleClashRule {
+    pub fn with_min_turnaround(mut self, min_turnaround: Duration) -> Self {
+        self.min_turnaround = min_turnaround;
+        self
+    }
+
+    pub fn with_venue(mut self, venue: &str, location: VenueLocation) -> Self {
+        self.venues.insert(venue.to_string(), location);
+        self
+    }
+
+    pub fn with_travel_speed(mut self, travel_speed_kmh: f64) -> Self {
+        self.travel_speed_kmh = travel_speed_kmh;
+        self
+    }
+
+    /// Normalised names of the teams playing (placeholder opponents excluded).
+    fn teams(fixture: &Fixture) -> Vec<String> {
+        let mut teams = vec![normalise_team(&fixture.team_label())];
+        if !placeholder_fields(fixture).contains(&FixtureField::Opponent) {
+            teams.push(normalise_team(&fixture.opponent));
+        }
+        teams
+    }
+
+    fn clash(&self, fixture: &Fixture, other: &Fixture) -> Option<ValidationIssue> {
+        let (first, second) = if fixture.datetime <= other.datetime {
+            (fixture, other)
+        } else {
+            (other, fixture)
+        };
+        let (_, first_end) = first.time_window();
+        let gap = second.datetime - first_end;
+
+        let other_kick_off = other.to_london_time().format("%a %d %b %H:%M");
+        let (severity, message) = if gap < Duration::zero() {
+            (
+                IssueSeverity::Error,
+                format!("Overlaps {} ({other_kick_off})", other.title()),
+            )
+        } else if gap < self.min_turnaround {
+            (
+                IssueSeverity::Warning,
+                format!(
+                    "Only {} between this and {} ({other_kick_off})",
+                    format_gap(gap),
+                    other.title()
+                ),
+            )
+        } else {
+            let from = self.venues.get(&first.venue)?;
+            let to = self.venues.get(&second.venue)?;
+            let distance = from.distance_km(to);
+            let travel = Duration::minutes((distance / self.travel_speed_kmh * 60.0) as i64);
+            if gap >= travel {
+                return None;
+            }
+            (
+                IssueSeverity::Warning,
+                format!(
+                    "{distance:.0} km from {} to {} in {} ({} at {other_kick_off})",
+                    first.venue,
+                    second.venue,
+                    format_gap(gap),
+                    other.title()
+                ),
+            )
+        };
+
+        Some(ValidationIssue {
+            severity,
+            category: IssueCategory::ScheduleClash,
+            message,
+            suggested_fix: Some(
+                "One of these fixtures is probably stale or misparsed; check both".to_string(),
+            ),
+        })
+    }
+}
+
+impl ValidationRule for ScheduleClashRule {
+    fn name(&self) -> &str {
+        "schedule_clash"
+    }
+
+    fn check(&self, fixture: &Fixture, context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        let Some(batch) = context.batch else {
+            return Vec::new();
+        };
+        // Called-off fixtures don't occupy their slot any more
+        if !fixture.sport.is_head_to_head() || !fixture.status.is_active() {
+            return Vec::new();
+        }
+
+        let teams = Self::teams(fixture);
+        batch
+            .iter()
+            .filter(|other| !std::ptr::eq(*other, fixture) && other.sport.is_head_to_head())
+            .filter(|other| other.status.is_active())
+            .filter(|other| Self::teams(other).iter().any(|team| teams.contains(team)))
+            .filter_map(|other| self.clash(fixture, other))
+            .collect()
+    }
+}
+
+/// Custom start-hour window for one sport and/or competition, in any timezone.
+///
+/// A Springboks fan might accept rugby from 06:00 to 22:00 Johannesburg time;
+/// fixtures the filters don't match are left alone.
+#[derive(Debug, Clone)]
+pub struct KickOffWindowRule {
+    name: String,
+    pub sport: Option<Sport>,
+    /// Only fixtures whose competition matches (see [`Fixture::competition_matches`])
+    pub competition: Option<String>,
+    pub timezone: Tz,
+    /// Inclusive start hours, e.g. 6..=22
+    pub start_hours: std::ops::RangeInclusive<u32>,
+}
+
+impl KickOffWindowRule {
+    pub fn new(timezone: Tz, start_hours: std::ops::RangeInclusive<u32>) -> Self {
+        Self {
+            name: "kick_off_window".to_string(),
+            sport: None,
+            competition: None,
+            timezone,
+            start_hours,
+        }
+    }
+
+    /// Start hours from config, which must be 0-23 and in order.
+    pub fn hours(
+        start_hour: u32,
+        end_hour: u32,
+    ) -> Result<std::ops::RangeInclusive<u32>, ScrapeError> {
+        if start_hour > 23 || end_hour > 23 || start_hour > end_hour {
+            return Err(ScrapeError::Parse(format!(
+                "Kick-off window hours must be 0-23 and in order, got {start_hour}-{end_hour}"
+            )));
+        }
+        Ok(start_hour..=end_hour)
+    }
+
+    pub fn for_sport(mut self, sport: Sport) -> Self {
+        self.sport = Some(sport);
+        self
+    }
+
+    pub fn for_competition(mut self, competition: &str) -> Self {
+        self.competition = Some(competition.to_string());
+        self
+    }
+
+    /// Rename the rule so several windows can live in one rule set.
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Whether the sport and competition filters match the fixture.
+    pub fn applies_to(&self, fixture: &Fixture) -> bool {
+        self.sport.is_none_or(|sport| sport == fixture.sport)
+            && self
+                .competition
+                .as_ref()
+                .is_none_or(|competition| fixture.competition_matches(competition))
+    }
+}
+
+impl ValidationRule for KickOffWindowRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, fixture: &Fixture, _context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        if !self.applies_to(fixture) {
+            return Vec::new();
+        }
+
+        let local = fixture.datetime.with_timezone(&self.timezone);
+        if self.start_hours.contains(&local.hour()) {
+            return Vec::new();
+        }
+
+        vec![ValidationIssue {
+            severity: IssueSeverity::Warning,
+            category: IssueCategory::SuspiciousTime,
+            message: format!(
+                "Start time {} {} is outside {:02}:00-{:02}:59",
+                local.format("%H:%M"),
+                self.timezone,
+                self.start_hours.start(),
+                self.start_hours.end()
+            ),
+            suggested_fix: Some("Verify the kick-off time with the competition".to_string()),
+        }]
+    }
+}
+
+/// Opponents in a competition must come from a known list (e.g. this season's league).
+#[derive(Debug, Clone)]
+pub struct OpponentListRule {
+    name: String,
+    /// e.g. "Premier League"; other competitions aren't checked
+    pub competition: String,
+    pub opponents: Vec<String>,
+}
+
+impl OpponentListRule {
+    pub fn new(competition: &str, opponents: Vec<String>) -> Self {
+        Self {
+            name: "opponent_list".to_string(),
+            competition: competition.to_string(),
+            opponents,
+        }
+    }
+
+    /// Rename the rule so several lists can live in one rule set.
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+impl ValidationRule for OpponentListRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, fixture: &Fixture, _context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+        let in_competition = fixture.competition_matches(&self.competition);
+        let known = self
+            .opponents
+            .iter()
+            .any(|opponent| opponent.eq_ignore_ascii_case(fixture.opponent.trim()));
+
+        if !in_competition || known {
+            return Vec::new();
+        }
+
+        vec![ValidationIssue {
+            severity: IssueSeverity::Error,
+            category: IssueCategory::DataInconsistency,
+            message: format!(
+                "{} is not a known {} opponent",
+                fixture.opponent, fixture.competition
+            ),
+            suggested_fix: Some("Check the opponent name or update the opponent list".to_string()),
+        }]
+    }
+}
+
+/// A rule set described as data, e.g. loaded from JSON.
+///
+/// ```json
+/// {
+///   "include_standard": true,
+///   "rules": [
+///     { "rule": "kick_off_window", "sport": "RugbyUnion",
+///       "timezone": "Africa/Johannesburg", "start_hour": 6, "end_hour": 22 },
+///     { "rule": "opponent_list", "competition": "Premier League",
+///       "opponents": ["Chelsea", "Liverpool"] }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RuleSetConfig {
+    /// Start from `RuleSet::standard()` (rules below replace same-named ones)
+    #[serde(default = "default_include_standard")]
+    pub include_standard: bool,
+    #[serde(default)]
+    pub rules: Vec<RuleConfig>,
+}
+
+fn default_include_standard() -> bool {
+    true
+}
+
+impl RuleSetConfig {
+    pub fn from_json(json: &str) -> Result<Self, ScrapeError> {
+        serde_json::from_str(json)
+            .map_err(|e| ScrapeError::Parse(format!("Invalid rule set config: {e}")))
+    }
+}
+
+/// One rule in a [`RuleSetConfig`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum RuleConfig {
+    DateRange {
+        #[serde(default)]
+        years_behind: i32,
+        years_ahead: i32,
+    },
+    WeekdayConsistency,
+    KickOffTime,
+    KickOffAgreement,
+    TimezoneResolution,
+    FixtureData,
+    FixtureStatus,
+    ScheduleClash {
+        #[serde(default)]
+        min_turnaround_hours: Option<i64>,
+        #[serde(default)]
+        venues: BTreeMap<String, VenueLocation>,
+        #[serde(default)]
+        travel_speed_kmh: Option<f64>,
+    },
+    Confidence {
+        thresholds: ConfidenceThresholds,
+    },
+    KickOffWindow {
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        sport: Option<Sport>,
+        #[serde(default)]
+        competition: Option<String>,
+        /// IANA timezone name, e.g. "Africa/Johannesburg"
+        timezone: String,
+        start_hour: u32,
+        end_hour: u32,
+    },
+    OpponentList {
+        #[serde(default)]
+        name: Option<String>,
+        competition: String,
+        opponents: Vec<String>,
+    },
+}
+
+impl RuleConfig {
+    pub fn build(&self) -> Result<Box<dyn ValidationRule>, ScrapeError> {
+        Ok(match self {
+            RuleConfig::DateRange {
+                years_behind,
+                years_ahead,
+            } => Box::new(DateRangeRule {
+                years_behind: *years_behind,
+                years_ahead: *years_ahead,
+            }),
+            RuleConfig::WeekdayConsistency => Box::new(WeekdayConsistencyRule),
+            RuleConfig::KickOffTime => Box::new(KickOffTimeRule::default()),
+            RuleConfig::KickOffAgreement => Box::new(KickOffAgreementRule),
+            RuleConfig::TimezoneResolution => Box::new(TimezoneResolutionRule),
+            RuleConfig::FixtureData => Box::new(FixtureDataRule),
+            RuleConfig::FixtureStatus => Box::new(FixtureStatusRule),
+            RuleConfig::ScheduleClash {
+                min_turnaround_hours,
+                venues,
+                travel_speed_kmh,
+            } => {
+                let mut rule = ScheduleClashRule {
+                    venues: venues.clone(),
+                    ..Default::default()
+                };
+                if let Some(hours) = min_turnaround_hours {
+                    rule.min_turnaround = Duration::hours(*hours);
+                }
+                if let Some(speed) = travel_speed_kmh {
+                    rule.travel_speed_kmh = *speed;
+                }
+                Box::new(rule)
+            }
+            RuleConfig::Confidence { thresholds } => Box::new(ConfidenceRule::new(*thresholds)),
+            RuleConfig::KickOffWindow {
+                name,
+                sport,
+                competition,
+                timezone,
+                start_hour,
+                end_hour,
+            } => {
+                let timezone: Tz = timezone.parse().map_err(|_| {
+                    ScrapeError::Parse(format!("Unknown timezone in rule config: {timezone}"))
+                })?;
+                let hours = KickOffWindowRule::hours(*start_hour, *end_hour)?;
+                let mut rule = KickOffWindowRule::new(timezone, hours);
+                rule.sport = *sport;
+                rule.competition = competition.clone();
+                if let Some(name) = name {
+                    rule = rule.named(name);
+                }
+                Box::new(rule)
+            }
+            RuleConfig::OpponentList {
+                name,
+                competition,
+                opponents,
+            } => {
+                let mut rule = OpponentListRule::new(competition, opponents.clone());
+                if let Some(name) = name {
+                    rule = rule.named(name);
+                }
+                Box::new(rule)
+            }
+        })
+    }
+}
+
+fn format_gap(gap: Duration) -> String {
+    format!("{}h {:02}m", gap.num_hours(), gap.num_minutes() % 60)
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::WeekdayMismatch;
+    use crate::test_support::{clock, fixture, utc};
+
+    /// Friday 15 Aug 2025, 17:30 BST
+    fn create_test_fixture() -> Fixture {
+        fixture().at(utc(2025, 8, 15, 16, 30)).build()
+    }
+
+    fn check(rule: &dyn ValidationRule, fixture: &Fixture) -> Vec<ValidationIssue> {
+        rule.check(fixture, &ValidationContext::new(&clock()))
+    }
+
+    #[test]
+    fn test_standard_rule_order() {
+        assert_eq!(
+            RuleSet::standard().rule_names(),
+            vec![
+                "date_range",
+                "weekday_consistency",
+                "kick_off_time",
+                "kick_off_agreement",
+                "timezone_resolution",
+                "fixture_data",
+                "fixture_status",
+                "schedule_clash",
+                "confidence",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_rule_replaces_by_name() {
+        let strict = ConfidenceThresholds {
+            critical_below: Some(0.99),
+            ..Default::default()
+        };
+        let rules = RuleSet::standard()
+            .with_rule(ConfidenceRule::new(strict))
+            .without_rule("kick_off_time");
+        assert_eq!(rules.rule_names().len(), 8);
+
+        let mut fixture = create_test_fixture();
+        fixture.venue = "TBD Venue".to_string();
+        assert!(matches!(
+            rules.validate(&fixture, &clock()),
+            FixtureValidation::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn test_localised_weekday_claim() {
+        let mut fixture = create_test_fixture();
+        fixture.parse_metadata.locale = Some(crate::locale::Locale::French);
+        fixture.parse_metadata.weekday_mismatch = Some(WeekdayMismatch {
+            claimed_weekday: "Dimanche".to_string(),
+            actual_weekday: "Friday".to_string(),
+            date: "15 août".to_string(),
+        });
+
+        assert_eq!(
+            WeekdayConsistencyRule::extract_expected_weekday(&fixture),
+            Some(Weekday::Sun)
+        );
+        assert_eq!(check(&WeekdayConsistencyRule, &fixture).len(), 1);
+    }
+
+    #[test]
+    fn test_timezone_resolution_warnings() {
+        use crate::parsing::{DstPolicy, DstResolution, DstTransition};
+
+        let mut fixture = create_test_fixture();
+        assert!(check(&TimezoneResolutionRule, &fixture).is_empty());
+
+        fixture.parse_metadata.dst_resolution = Some(DstResolution {
+            transition: DstTransition::Ambiguous,
+            policy: DstPolicy::ShiftForward,
+            local_time: chrono::NaiveDate::from_ymd_opt(2025, 10, 26)
+                .unwrap()
+                .and_hms_opt(1, 30, 0)
+                .unwrap(),
+            timezone: "Europe/London".to_string(),
+            resolved_offset: "+01:00".to_string(),
+        });
+        fixture.parse_metadata.parsing_strategy =
+            ParsingStrategy::TimezoneFallback("UTC".to_string());
+
+        let issues = check(&TimezoneResolutionRule, &fixture);
+        assert_eq!(issues.len(), 2);
+        assert!(issues
+            .iter()
+            .all(|i| i.category == IssueCategory::TimezoneResolution
+                && i.severity == IssueSeverity::Warning));
+        assert!(issues[0].message.contains("occurs twice"));
+    }
+
+    #[test]
+    fn test_confidence_thresholds_set_severity() {
+        let mut fixture = create_test_fixture();
+        assert!(check(&ConfidenceRule::default(), &fixture).is_empty());
+
+        // Year assumption (0.75) plus placeholder venue (0.9) = 0.675
+        fixture.parse_metadata.parsing_strategy = ParsingStrategy::YearAssumption(2026);
+        fixture.venue = "TBD Venue".to_string();
+
+        let issues = check(&ConfidenceRule::default(), &fixture);
+        assert_eq!(issues[0].category, IssueCategory::LowConfidence);
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+
+        let strict = ConfidenceRule::new(ConfidenceThresholds {
+            error_below: Some(0.7),
+            ..Default::default()
+        });
+        assert_eq!(check(&strict, &fixture)[0].severity, IssueSeverity::Error);
+    }
+
+    #[test]
+    fn test_kick_off_sources_disagree() {
+        use crate::parsing::{KickOffReading, KickOffSource};
+
+        let mut fixture = create_test_fixture();
+        let reading = |source, hour| KickOffReading {
+            source,
+            text: String::new(),
+            parsed: Some(utc(2025, 8, 15, hour, 30)),
+        };
+
+        fixture.parse_metadata.kick_off_readings = vec![
+            reading(KickOffSource::DatetimeAttribute, 16),
+            reading(KickOffSource::HiddenHeader, 16),
+        ];
+        assert!(check(&KickOffAgreementRule, &fixture).is_empty());
+
+        // Visible text is an hour late; unparsed readings are ignored
+        fixture
+            .parse_metadata
+            .kick_off_readings
+            .push(reading(KickOffSource::VisibleText, 17));
+        fixture
+            .parse_metadata
+            .kick_off_readings
+            .push(KickOffReading {
+                source: KickOffSource::VisibleText,
+                text: "TBC".to_string(),
+                parsed: None,
+            });
+
+        let issues = check(&KickOffAgreementRule, &fixture);
+        assert_eq!(issues[0].category, IssueCategory::SourceDisagreement);
+        assert_eq!(
+            issues[0].message,
+            "Kick-off sources disagree: datetime attribute says Fri 15 Aug 17:30, visible text says Fri 15 Aug 18:30 (London time)"
+        );
+    }
+
+    #[test]
+    fn test_suspicious_time_depends_on_sport() {
+        use crate::sport::{MotorsportSession, SportDetails};
+
+        // 4 AM BST is odd for football but normal for an F1 race in Asia
+        let mut fixture = create_test_fixture().with_sport_details(SportDetails::Motorsport {
+            series: "Formula 1".to_string(),
+            session: MotorsportSession::Race,
+        });
+        fixture.datetime = utc(2099, 8, 15, 3, 0);
+        assert!(check(&KickOffTimeRule::default(), &fixture).is_empty());
+
+        fixture.sport = Sport::Football;
+        fixture.sport_details = None;
+        assert_eq!(check(&KickOffTimeRule::default(), &fixture).len(), 1);
+    }
+
+    #[test]
+    fn test_sport_details_mismatch() {
+        use crate::sport::{CricketFormat, SportDetails};
+
+        let mut fixture = create_test_fixture().with_sport_details(SportDetails::Cricket {
+            format: CricketFormat::TEST,
+        });
+        fixture.sport = Sport::Football;
+
+        assert!(check(&FixtureDataRule, &fixture)
+            .iter()
+            .any(|i| i.category == IssueCategory::DataInconsistency));
+    }
+
+    #[test]
+    fn test_fixture_status_notes() {
+        let fixture = create_test_fixture();
+        assert!(check(&FixtureStatusRule, &fixture).is_empty());
+
+        let rescheduled = fixture.clone().with_status(FixtureStatus::Rescheduled {
+            previous: utc(2025, 8, 16, 14, 0),
+        });
+        let issues = check(&FixtureStatusRule, &rescheduled);
+        assert_eq!(issues[0].category, IssueCategory::StatusChange);
+        assert_eq!(
+            issues[0].message,
+            "Kick-off moved from Sat 16 Aug 15:00 (London time)"
+        );
+
+        // A cancelled fixture can't clash with anything
+        let mut replacement = fixture.clone();
+        replacement.opponent = "Liverpool".to_string();
+        let batch = vec![fixture.with_status(FixtureStatus::Cancelled), replacement];
+        let clock = clock();
+        let context = ValidationContext::new(&clock).with_batch(&batch);
+        assert!(ScheduleClashRule::default()
+            .check(&batch[1], &context)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_schedule_clash_needs_batch() {
+        let fixture = create_test_fixture();
+        assert!(check(&ScheduleClashRule::default(), &fixture).is_empty());
+    }
+
+    #[test]
+    fn test_schedule_clash_overlap_and_turnaround() {
+        let first = create_test_fixture(); // Fri 17:30 BST
+        let mut overlapping = create_test_fixture();
+        overlapping.opponent = "Liverpool".to_string();
+        overlapping.datetime += Duration::hours(1);
+        let mut next_morning = create_test_fixture();
+        next_morning.opponent = "Fulham".to_string();
+        next_morning.datetime += Duration::hours(18);
+        let mut other_team = create_test_fixture();
+        other_team.team = "Tottenham".to_string();
+        other_team.opponent = "Brentford".to_string();
+
+        let validated = RuleSet::new()
+            .with_rule(ScheduleClashRule::default())
+            .validate_all(vec![first, overlapping, next_morning, other_team], &clock());
+
+        let FixtureValidation::ValidWithWarnings(issues) = &validated[0].validation else {
+            panic!("Expected clashes for the first fixture");
+        };
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].severity, IssueSeverity::Error);
+        assert_eq!(
+            issues[0].message,
+            "Overlaps Arsenal vs Liverpool (Fri 15 Aug 18:30)"
+        );
+        assert_eq!(issues[1].severity, IssueSeverity::Warning);
+        assert_eq!(
+            issues[1].message,
+            "Only 16h 05m between this and Arsenal vs Fulham (Sat 16 Aug 11:30)"
+        );
+
+        assert_eq!(validated[3].validation, FixtureValidation::Valid);
+    }
+
+    #[test]
+    fn test_schedule_clash_travel_time() {
+        let home = create_test_fixture();
+        let mut away = create_test_fixture();
+        away.opponent = "Sporting CP".to_string();
+        away.venue = "Estádio José Alvalade".to_string();
+        away.datetime += Duration::hours(27);
+
+        let rule = ScheduleClashRule::default()
+            .with_venue("Emirates Stadium", VenueLocation::new(51.555, -0.108))
+            .with_venue("Estádio José Alvalade", VenueLocation::new(38.761, -9.161))
+            .with_travel_speed(50.0);
+        let batch = vec![home, away];
+        let clock = clock();
+        let context = ValidationContext::new(&clock).with_batch(&batch);
+
+        let issues = rule.check(&batch[1], &context);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].message,
+            "1587 km from Emirates Stadium to Estádio José Alvalade in 25h 05m (Arsenal vs Chelsea at Fri 15 Aug 17:30)"
+        );
+
+        // At the default speed the trip fits in the gap
+        let rule = ScheduleClashRule {
+            travel_speed_kmh: 500.0,
+            ..rule
+        };
+        assert!(rule.check(&batch[1], &context).is_empty());
+    }
+
+    #[test]
+    fn test_kick_off_window_rule() {
+        // 16:30 UTC is 18:30 in Johannesburg
+        let rule = KickOffWindowRule::new(chrono_tz::Africa::Johannesburg, 6..=17)
+            .for_competition("premier league");
+        assert_eq!(check(&rule, &create_test_fixture()).len(), 1);
+
+        let rugby_only = rule.clone().for_sport(Sport::RugbyUnion);
+        assert!(check(&rugby_only, &create_test_fixture()).is_empty());
+    }
+
+    #[test]
+    fn test_rule_set_from_config() {
+        let config = RuleSetConfig::from_json(
+            r#"{
+                "rules": [
+                    { "rule": "opponent_list", "competition": "Premier League",
+                      "opponents": ["Liverpool", "Manchester City"] },
+                    { "rule": "kick_off_window", "name": "sa_rugby", "sport": "RugbyUnion",
+                      "timezone": "Africa/Johannesburg", "start_hour": 6, "end_hour": 22 }
+                ]
+            }"#,
+        )
+        .expect("Config should parse");
+
+        let rules = RuleSet::from_config(&config).expect("Rules should build");
+        assert_eq!(rules.rule_names().len(), 11);
+        assert!(rules.rule_names().contains(&"sa_rugby"));
+
+        // Chelsea isn't on the configured list
+        let FixtureValidation::ValidWithWarnings(issues) =
+            rules.validate(&create_test_fixture(), &clock())
+        else {
+            panic!("Expected the opponent list to flag Chelsea");
+        };
+        assert!(issues[0]
+            .message
+            .contains("not a known Premier League opponent"));
+
+        let bad = RuleSetConfig::from_json(
+            r#"{ "include_standard": false, "rules": [
+                { "rule": "kick_off_window", "timezone": "Mars/Olympus", "start_hour": 6, "end_hour": 22 }
+            ] }"#,
+        )
+        .unwrap();
+        assert!(RuleSet::from_config(&bad).is_err());
+
+        for (start_hour, end_hour) in [(6, 25), (22, 6)] {
+            let window = RuleSetConfig {
+                include_standard: false,
+                rules: vec![RuleConfig::KickOffWindow {
+                    name: None,
+                    sport: None,
+                    competition: None,
+                    timezone: "Europe/London".to_string(),
+                    start_hour,
+                    end_hour,
+                }],
+            };
+            assert!(RuleSet::from_config(&window).is_err());
+        }
+    }
+
+    #[test]
+    fn test_batch_is_visible_to_rules() {
+        struct BatchSize;
+        impl ValidationRule for BatchSize {
+            fn name(&self) -> &str {
+                "batch_size"
+            }
+
+            fn check(&self, _: &Fixture, context: &ValidationContext<'_>) -> Vec<ValidationIssue> {
+                let size = context.batch.map_or(0, |batch| batch.len());
+                vec![ValidationIssue {
+                    severity: IssueSeverity::Warning,
+                    category: IssueCategory::DataInconsistency,
+                    message: format!("batch of {size}"),
+                    suggested_fix: None,
+                }]
+            }
+        }
+
+        let validated = RuleSet::new()
+            .with_rule(BatchSize)
+            .validate_all(vec![create_test_fixture(), create_test_fixture()], &clock());
+        let FixtureValidation::ValidWithWarnings(issues) = &validated[1].validation else {
+            panic!("Expected the custom rule to run");
+        };
+        assert_eq!(issues[0].message, "batch of 2");
+    }
+}
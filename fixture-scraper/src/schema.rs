@@ -0,0 +1,273 @@
+//! # Versioned Fixture Files
+//!
+//! `calpal scrape --output` used to write a bare `Vec<ValidatedFixture>`, so
+//! any change to `Fixture` broke older files and everything reading them.
+//! Files are now a [`FixtureFile`] envelope:
+//!
+//! ```json
+//! {
+//!   "schema_version": 2,
+//!   "generated_at": "2025-08-08T12:00:00Z",
+//!   "scrapers": [ { "name": "Arsenal", "version": "0.1.0" } ],
+//!   "sources": [ { "name": "Arsenal", "url": "https://www.arsenal.com/fixtures" } ],
+//!   "fixtures": [ ... ]
+//! }
+//! ```
+//!
+//! The published contract is [`JSON_SCHEMA`] (`schema/fixtures-v2.schema.json`).
+//! [`FixtureFile::from_json`] reads any earlier version and upgrades it step by
+//! step, so scripts only ever see the current layout.
+//!
+//! ## Versions
+//!
+//! 1. A bare array of validated fixtures (before the envelope)
+//! 2. The envelope above
+
+use crate::validation::ValidatedFixture;
+use crate::{FixtureSource, ScrapeError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// The layout written by this version of the library.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// JSON Schema for [`SCHEMA_VERSION`].
+pub const JSON_SCHEMA: &str = include_str!("../schema/fixtures-v2.schema.json");
+
+/// Library version recorded against each scraper in a file.
+pub const SCRAPER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Upgrades from version `n + 1` to `n + 2`, in order.
+const MIGRATIONS: [fn(Value) -> Value; 1] = [wrap_bare_array];
+
+/// A scraper that contributed to a file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScraperInfo {
+    pub name: String,
+    pub version: String,
+}
+
+/// Top-level layout of a saved fixture file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FixtureFile {
+    pub schema_version: u32,
+    pub generated_at: DateTime<Utc>,
+    pub scrapers: Vec<ScraperInfo>,
+    /// Every page the fixtures came from
+    pub sources: Vec<FixtureSource>,
+    pub fixtures: Vec<ValidatedFixture>,
+}
+
+impl FixtureFile {
+    /// Wrap `fixtures`, listing the pages they came from.
+    pub fn new(fixtures: Vec<ValidatedFixture>, generated_at: DateTime<Utc>) -> Self {
+        let mut sources: Vec<FixtureSource> = Vec::new();
+        for source in fixtures.iter().flat_map(|v| &v.fixture.sources) {
+            let page = FixtureSource::new(&source.name, &source.url);
+            if !sources.contains(&page) {
+                sources.push(page);
+            }
+        }
+
+        Self {
+            schema_version: SCHEMA_VERSION,
+            generated_at,
+            scrapers: Vec::new(),
+            sources,
+            fixtures,
+        }
+    }
+
+    pub fn with_scraper(mut self, name: &str, version: &str) -> Self {
+        self.scrapers.push(ScraperInfo {
+            name: name.to_string(),
+            version: version.to_string(),
+        });
+        self
+    }
+
+    /// Read a file of any known version, upgrading it to [`SCHEMA_VERSION`].
+    pub fn from_json(json: &str) -> Result<Self, ScrapeError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| ScrapeError::Parse(format!("Invalid fixture file: {e}")))?;
+        let value = upgrade(value)?;
+
+        serde_json::from_value(value).map_err(|e| {
+            ScrapeError::Parse(format!(
+                "Fixture file doesn't match schema version {SCHEMA_VERSION}: {e}"
+            ))
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ScrapeError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| ScrapeError::Parse(format!("Cannot read {}: {e}", path.display())))?;
+        Self::from_json(&json)
+    }
+
+    pub fn to_json(&self, pretty: bool) -> Result<String, ScrapeError> {
+        let json = if pretty {
+            serde_json::to_string_pretty(self)
+        } else {
+            serde_json::to_string(self)
+        };
+        json.map_err(|e| ScrapeError::Parse(format!("Cannot serialise fixture file: {e}")))
+    }
+}
+
+/// The schema version of a parsed file; bare arrays predate the version field,
+/// so an envelope claiming version 1 (or 0) is rejected.
+fn version_of(value: &Value) -> Result<u32, ScrapeError> {
+    match value {
+        Value::Array(_) => Ok(1),
+        Value::Object(object) => {
+            let version = object
+                .get("schema_version")
+                .and_then(Value::as_u64)
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or_else(|| {
+                    ScrapeError::Parse("Fixture file has no schema_version".to_string())
+                })?;
+            if version < 2 {
+                return Err(ScrapeError::Parse(format!(
+                    "Fixture file claims schema version {version}, but envelopes start at 2"
+                )));
+            }
+            Ok(version)
+        }
+        _ => Err(ScrapeError::Parse(
+            "Fixture file must be an object or an array".to_string(),
+        )),
+    }
+}
+
+fn upgrade(mut value: Value) -> Result<Value, ScrapeError> {
+    let version = version_of(&value)?;
+    if version > SCHEMA_VERSION {
+        return Err(ScrapeError::Parse(format!(
+            "Fixture file uses schema version {version}; this calpal reads up to {SCHEMA_VERSION}"
+        )));
+    }
+
+    for migration in &MIGRATIONS[(version - 1) as usize..] {
+        value = migration(value);
+    }
+    Ok(value)
+}
+
+/// 1 → 2: wrap the bare array in an envelope. Generation time and scrapers
+/// weren't recorded, so the epoch and an empty list stand in.
+fn wrap_bare_array(fixtures: Value) -> Value {
+    let mut sources: Vec<Value> = Vec::new();
+    for source in fixtures
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|validated| validated.pointer("/fixture/sources")?.as_array())
+        .flatten()
+    {
+        let page = json!({ "name": source["name"], "url": source["url"] });
+        if !sources.contains(&page) {
+            sources.push(page);
+        }
+    }
+
+    json!({
+        "schema_version": 2,
+        "generated_at": DateTime::<Utc>::UNIX_EPOCH,
+        "scrapers": [],
+        "sources": sources,
+        "fixtures": fixtures,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{fixture, now};
+
+    fn validated() -> ValidatedFixture {
+        fixture().on_arsenal_page().validated()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let file =
+            FixtureFile::new(vec![validated()], now()).with_scraper("Arsenal", SCRAPER_VERSION);
+
+        assert_eq!(
+            file.sources,
+            vec![FixtureSource::new(
+                "Arsenal",
+                "https://www.arsenal.com/fixtures"
+            )]
+        );
+        let json = file.to_json(false).unwrap();
+        assert!(json.starts_with(r#"{"schema_version":2,"#));
+        assert_eq!(FixtureFile::from_json(&json).unwrap(), file);
+    }
+
+    #[test]
+    fn test_upgrades_bare_array() {
+        let legacy = serde_json::to_string(&vec![validated()]).unwrap();
+        let file = FixtureFile::from_json(&legacy).unwrap();
+
+        assert_eq!(file.schema_version, SCHEMA_VERSION);
+        assert_eq!(file.generated_at, DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(file.fixtures, vec![validated()]);
+        assert_eq!(file.sources.len(), 1);
+
+        // Version 1 files predate sources and status; defaults fill them in
+        let older = r#"[{ "fixture": { "team": "Arsenal", "opponent": "Newcastle",
+            "datetime": "2025-09-27T16:30:00Z", "venue": "St James' Park",
+            "competition": "Premier League",
+            "parse_metadata": { "original_source": "", "weekday_mismatch": null,
+              "timezone_assumptions": "", "parsing_strategy": "ExactMatch" } },
+            "validation": "Valid" }]"#;
+        let file = FixtureFile::from_json(older).unwrap();
+        assert_eq!(file.fixtures[0].fixture.opponent, "Newcastle");
+        assert!(file.sources.is_empty());
+    }
+
+    #[test]
+    fn test_rejects_unknown_versions() {
+        let newer = r#"{ "schema_version": 99, "fixtures": [] }"#;
+        assert!(FixtureFile::from_json(newer)
+            .unwrap_err()
+            .to_string()
+            .contains("schema version 99"));
+        assert!(FixtureFile::from_json(r#"{ "fixtures": [] }"#).is_err());
+        assert!(
+            FixtureFile::from_json(r#"{ "schema_version": 0, "fixtures": [] }"#)
+                .unwrap_err()
+                .to_string()
+                .contains("schema version 0")
+        );
+        // Version 1 was a bare array, never an envelope
+        assert!(
+            FixtureFile::from_json(r#"{ "schema_version": 1, "fixtures": [] }"#)
+                .unwrap_err()
+                .to_string()
+                .contains("schema version 1")
+        );
+        assert!(FixtureFile::from_json("42").is_err());
+    }
+
+    #[test]
+    fn test_published_schema_matches_version() {
+        let schema: Value = serde_json::from_str(JSON_SCHEMA).unwrap();
+        assert_eq!(
+            schema.pointer("/properties/schema_version/const"),
+            Some(&json!(SCHEMA_VERSION))
+        );
+
+        // Every top-level field we write is required by the schema
+        let written = serde_json::to_value(FixtureFile::new(Vec::new(), Utc::now())).unwrap();
+        let required = schema["required"].as_array().unwrap();
+        for key in written.as_object().unwrap().keys() {
+            assert!(required.contains(&json!(key)), "{key} missing from schema");
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! # Season Context for Year Inference
+//!
+//! Fixture lists rarely print the year: "Sat 17 Jan" on a page scraped in
+//! August means January of *next* year. A [`Season`] tells the parser which
+//! months belong to which calendar year, so a 2025/26 football season reads
+//! August–December as 2025 and January–May as 2026, while a Super Rugby or
+//! county cricket season simply stays within one calendar year.
+//!
+//! Months outside the season (the summer break) are assigned to whichever end
+//! of the season they are closer to: June follows the May finish, July is
+//! pre-season for the August start.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A competition season, described by the year it starts and its first and last months.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Season {
+    pub start_year: i32,
+    /// First month of the season (1-12)
+    pub start_month: u32,
+    /// Last month of the season (1-12); earlier than `start_month` when it spans new year
+    pub end_month: u32,
+}
+
+impl Season {
+    pub fn new(start_year: i32, start_month: u32, end_month: u32) -> Self {
+        Self {
+            start_year,
+            start_month,
+            end_month,
+        }
+    }
+
+    /// European football season, August to May (`football(2025)` is 2025/26).
+    pub fn football(start_year: i32) -> Self {
+        Self::new(start_year, 8, 5)
+    }
+
+    /// Season played within one calendar year (Super Rugby, the County Championship).
+    pub fn calendar_year(year: i32) -> Self {
+        Self::new(year, 1, 12)
+    }
+
+    /// Whether the season crosses new year.
+    pub fn spans_new_year(&self) -> bool {
+        self.end_month < self.start_month
+    }
+
+    /// Calendar years the season touches, starting year first.
+    pub fn years(&self) -> Vec<i32> {
+        if self.spans_new_year() {
+            vec![self.start_year, self.start_year + 1]
+        } else {
+            vec![self.start_year]
+        }
+    }
+
+    /// Calendar year a fixture in `month` (1-12) belongs to.
+    pub fn year_for_month(&self, month: u32) -> i32 {
+        if !self.spans_new_year() || month >= self.start_month {
+            return self.start_year;
+        }
+        if month <= self.end_month {
+            return self.start_year + 1;
+        }
+
+        // Off-season: attach to the nearer end of the season
+        let after_end = month - self.end_month;
+        let before_start = self.start_month - month;
+        if after_end <= before_start {
+            self.start_year + 1
+        } else {
+            self.start_year
+        }
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.spans_new_year() {
+            write!(
+                f,
+                "{}/{:02}",
+                self.start_year,
+                (self.start_year + 1).rem_euclid(100)
+            )
+        } else {
+            write!(f, "{}", self.start_year)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_football_season_years() {
+        let season = Season::football(2025);
+        assert_eq!(season.to_string(), "2025/26");
+        assert_eq!(season.year_for_month(8), 2025);
+        assert_eq!(season.year_for_month(12), 2025);
+        assert_eq!(season.year_for_month(1), 2026);
+        assert_eq!(season.year_for_month(5), 2026);
+        // Off-season months attach to the nearer end
+        assert_eq!(season.year_for_month(6), 2026);
+        assert_eq!(season.year_for_month(7), 2025);
+    }
+
+    #[test]
+    fn test_calendar_year_season() {
+        let season = Season::calendar_year(2026);
+        assert_eq!(season.to_string(), "2026");
+        assert_eq!(season.years(), vec![2026]);
+        assert_eq!(season.year_for_month(1), 2026);
+        assert_eq!(season.year_for_month(12), 2026);
+    }
+}
@@ -0,0 +1,259 @@
+//! # Multi-Sport Domain Model
+//!
+//! CalPal started as a football scraper, but a friend group's calendar mixes
+//! Premier League games with Springboks tests, cricket Tests and F1 weekends.
+//! This module describes *what kind* of event a fixture is so that validation
+//! and display can branch on sport instead of assuming a 90-minute football match.
+//!
+//! ## Design
+//!
+//! - [`Sport`] is a small `Copy` enum, cheap to match on and to use as a config key
+//! - [`SportDetails`] carries the sport-specific extras (rugby code, test vs
+//!   tour match, cricket format, motorsport session); its `Sport` is derived
+//!   from them, so the two can't disagree
+//! - Sport-specific rules (reasonable start hours, whether there is an opponent)
+//!   live on `Sport` so validators and displays ask the same question
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The sport a fixture belongs to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum Sport {
+    #[default]
+    Football,
+    RugbyUnion,
+    RugbyLeague,
+    Cricket,
+    Motorsport,
+}
+
+impl Sport {
+    /// Start hours (London time) that look plausible for this sport.
+    ///
+    /// Returns `None` when any hour is plausible: cricket in Australia and
+    /// F1 races in Asia regularly start in the middle of the London night.
+    pub fn reasonable_start_hours(&self) -> Option<RangeInclusive<u32>> {
+        match self {
+            Sport::Football => Some(8..=23),
+            // Southern hemisphere tests kick off early on a UK morning
+            Sport::RugbyUnion | Sport::RugbyLeague => Some(5..=23),
+            Sport::Cricket | Sport::Motorsport => None,
+        }
+    }
+
+    /// Whether events are contested between a team and a single opponent.
+    ///
+    /// Motorsport sessions have a whole field instead, so the fixture's
+    /// `opponent` holds the event name (e.g. "British Grand Prix").
+    pub fn is_head_to_head(&self) -> bool {
+        !matches!(self, Sport::Motorsport)
+    }
+}
+
+impl fmt::Display for Sport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sport::Football => write!(f, "Football"),
+            Sport::RugbyUnion => write!(f, "Rugby Union"),
+            Sport::RugbyLeague => write!(f, "Rugby League"),
+            Sport::Cricket => write!(f, "Cricket"),
+            Sport::Motorsport => write!(f, "Motorsport"),
+        }
+    }
+}
+
+/// Sport-specific extensions to a fixture.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum SportDetails {
+    /// Rugby union or league match type
+    Rugby {
+        // Files written before the code had its own type called this `sport`
+        #[serde(alias = "sport")]
+        code: RugbyCode,
+        match_type: RugbyMatchType,
+    },
+    /// Cricket match format (Tests run over several days)
+    Cricket { format: CricketFormat },
+    /// Motorsport session within a race weekend
+    Motorsport {
+        series: String,
+        session: MotorsportSession,
+    },
+}
+
+impl SportDetails {
+    /// The sport these details describe.
+    pub fn sport(&self) -> Sport {
+        match self {
+            SportDetails::Rugby { code, .. } => code.sport(),
+            SportDetails::Cricket { .. } => Sport::Cricket,
+            SportDetails::Motorsport { .. } => Sport::Motorsport,
+        }
+    }
+}
+
+impl fmt::Display for SportDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SportDetails::Rugby { match_type, .. } => write!(f, "{match_type}"),
+            SportDetails::Cricket { format } => write!(f, "{format}"),
+            SportDetails::Motorsport { series, session } => write!(f, "{series} {session}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RugbyCode {
+    #[serde(alias = "RugbyUnion")]
+    Union,
+    #[serde(alias = "RugbyLeague")]
+    League,
+}
+
+impl RugbyCode {
+    pub fn sport(&self) -> Sport {
+        match self {
+            RugbyCode::Union => Sport::RugbyUnion,
+            RugbyCode::League => Sport::RugbyLeague,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RugbyMatchType {
+    /// Full international test match
+    Test,
+    /// Touring side against a club or invitational team
+    TourMatch,
+}
+
+impl fmt::Display for RugbyMatchType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RugbyMatchType::Test => write!(f, "Test match"),
+            RugbyMatchType::TourMatch => write!(f, "Tour match"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum CricketFormat {
+    /// Multi-day format with the scheduled number of days (5 for a Test)
+    MultiDay { days: u8 },
+    /// 50-over one-day match
+    OneDay,
+    /// 20-over match
+    T20,
+}
+
+impl CricketFormat {
+    /// Standard five-day Test match.
+    pub const TEST: CricketFormat = CricketFormat::MultiDay { days: 5 };
+
+    /// Number of scheduled playing days.
+    pub fn days(&self) -> u8 {
+        match self {
+            CricketFormat::MultiDay { days } => *days,
+            CricketFormat::OneDay | CricketFormat::T20 => 1,
+        }
+    }
+}
+
+impl fmt::Display for CricketFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CricketFormat::MultiDay { days: 5 } => write!(f, "Test (5 days)"),
+            CricketFormat::MultiDay { days } => write!(f, "Multi-day ({days} days)"),
+            CricketFormat::OneDay => write!(f, "One-day"),
+            CricketFormat::T20 => write!(f, "T20"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MotorsportSession {
+    Practice(u8),
+    Qualifying,
+    SprintQualifying,
+    Sprint,
+    Race,
+}
+
+impl fmt::Display for MotorsportSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MotorsportSession::Practice(n) => write!(f, "Practice {n}"),
+            MotorsportSession::Qualifying => write!(f, "Qualifying"),
+            MotorsportSession::SprintQualifying => write!(f, "Sprint Qualifying"),
+            MotorsportSession::Sprint => write!(f, "Sprint"),
+            MotorsportSession::Race => write!(f, "Race"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sport_start_hours() {
+        assert_eq!(Sport::Football.reasonable_start_hours(), Some(8..=23));
+        assert!(Sport::RugbyUnion
+            .reasonable_start_hours()
+            .unwrap()
+            .contains(&6));
+        assert!(Sport::Motorsport.reasonable_start_hours().is_none());
+        assert!(Sport::Cricket.reasonable_start_hours().is_none());
+    }
+
+    #[test]
+    fn test_details_agree_with_sport() {
+        let rugby = SportDetails::Rugby {
+            code: RugbyCode::Union,
+            match_type: RugbyMatchType::Test,
+        };
+        let cricket = SportDetails::Cricket {
+            format: CricketFormat::TEST,
+        };
+        let f1 = SportDetails::Motorsport {
+            series: "Formula 1".to_string(),
+            session: MotorsportSession::Qualifying,
+        };
+
+        assert_eq!(rugby.sport(), Sport::RugbyUnion);
+        assert_eq!(RugbyCode::League.sport(), Sport::RugbyLeague);
+        assert_eq!(cricket.sport(), Sport::Cricket);
+        assert_eq!(f1.sport(), Sport::Motorsport);
+        assert!(!f1.sport().is_head_to_head());
+    }
+
+    #[test]
+    fn test_details_display() {
+        let cricket = SportDetails::Cricket {
+            format: CricketFormat::TEST,
+        };
+        let f1 = SportDetails::Motorsport {
+            series: "Formula 1".to_string(),
+            session: MotorsportSession::Practice(2),
+        };
+
+        assert_eq!(cricket.to_string(), "Test (5 days)");
+        assert_eq!(CricketFormat::TEST.days(), 5);
+        assert_eq!(f1.to_string(), "Formula 1 Practice 2");
+    }
+
+    #[test]
+    fn test_rugby_details_read_old_files() {
+        let old = r#"{ "Rugby": { "sport": "RugbyLeague", "match_type": "TourMatch" } }"#;
+        let details: SportDetails = serde_json::from_str(old).unwrap();
+        assert_eq!(
+            details,
+            SportDetails::Rugby {
+                code: RugbyCode::League,
+                match_type: RugbyMatchType::TourMatch,
+            }
+        );
+    }
+}
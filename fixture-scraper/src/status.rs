@@ -0,0 +1,287 @@
+//! # Fixture Lifecycle Status
+//!
+//! A fixture isn't only "upcoming" or "past": matches get postponed ("P-P"),
+//! cancelled, abandoned part-way, moved to a new kick-off, and eventually
+//! played. [`FixtureStatus`] records which, so calendars can show a
+//! cancelled match as CANCELLED instead of the event silently vanishing.
+//!
+//! Status comes from two places:
+//!
+//! - **Source markup**: [`FixtureStatus::from_label`] reads a card's status
+//!   label ("P-P", "FT"), and [`FixtureStatus::from_markup`] the unambiguous
+//!   words ("Postponed") printed anywhere on it
+//! - **Previous scrapes**: [`reconcile`] compares a new scrape with the last
+//!   one, marking moved kick-offs as rescheduled, fixtures the source dropped
+//!   as cancelled, and finished ones as completed
+
+use crate::changes::DiffEngine;
+use crate::Fixture;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Where a fixture is in its lifecycle.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum FixtureStatus {
+    /// Going ahead as listed
+    #[default]
+    Scheduled,
+    /// Called off; a new date may follow
+    Postponed,
+    /// Called off for good, or dropped by the source
+    Cancelled,
+    /// Started but not finished
+    Abandoned,
+    /// Going ahead at a different kick-off than previously listed
+    Rescheduled { previous: DateTime<Utc> },
+    /// Played
+    Completed,
+}
+
+impl FixtureStatus {
+    /// Status from a fixture card's status element, e.g. "P-P", "FT 2-1" or
+    /// "Match Cancelled".
+    ///
+    /// Returns `None` when the label carries no status, which callers should
+    /// read as `Scheduled`.
+    pub fn from_label(label: &str) -> Option<Self> {
+        let words = words(label);
+        let has_code = |codes: &[&str]| words.iter().any(|word| codes.contains(&word.as_str()));
+
+        if has_code(&["p-p", "pp"]) {
+            Some(FixtureStatus::Postponed)
+        } else if has_code(&["c-c"]) {
+            Some(FixtureStatus::Cancelled)
+        } else if has_code(&["a-a"]) {
+            Some(FixtureStatus::Abandoned)
+        } else if has_code(&["ft", "aet"]) || label.to_lowercase().contains("full time") {
+            Some(FixtureStatus::Completed)
+        } else {
+            Self::from_markup(label)
+        }
+    }
+
+    /// Status spelled out anywhere on a fixture card, e.g. "Match Postponed".
+    ///
+    /// Only words no team, venue or competition name would use count; short
+    /// codes like "PP" or "FT" are left to [`from_label`](Self::from_label).
+    pub fn from_markup(text: &str) -> Option<Self> {
+        let words = words(text);
+        let has_word =
+            |candidates: &[&str]| words.iter().any(|word| candidates.contains(&word.as_str()));
+
+        if has_word(&["postponed"]) {
+            Some(FixtureStatus::Postponed)
+        } else if has_word(&["cancelled", "canceled"]) {
+            Some(FixtureStatus::Cancelled)
+        } else if has_word(&["abandoned"]) {
+            Some(FixtureStatus::Abandoned)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the fixture is still expected to be played as listed.
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            FixtureStatus::Scheduled | FixtureStatus::Rescheduled { .. }
+        )
+    }
+
+    /// Whether the fixture was called off (postponed, cancelled or abandoned).
+    pub fn is_called_off(&self) -> bool {
+        matches!(
+            self,
+            FixtureStatus::Postponed | FixtureStatus::Cancelled | FixtureStatus::Abandoned
+        )
+    }
+}
+
+impl fmt::Display for FixtureStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixtureStatus::Scheduled => write!(f, "Scheduled"),
+            FixtureStatus::Postponed => write!(f, "Postponed"),
+            FixtureStatus::Cancelled => write!(f, "Cancelled"),
+            FixtureStatus::Abandoned => write!(f, "Abandoned"),
+            FixtureStatus::Rescheduled { .. } => write!(f, "Rescheduled"),
+            FixtureStatus::Completed => write!(f, "Completed"),
+        }
+    }
+}
+
+/// Lowercase words of `text`, keeping hyphenated codes like "p-p" whole.
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '-')
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Update statuses in `current` by comparing it with the `previous` scrape.
+///
+/// Fixtures are paired the same way [`DiffEngine`] pairs them.
+///
+/// - A fixture whose kick-off moved becomes `Rescheduled` (keeping the
+///   earliest known kick-off if it moves again)
+/// - A fixture that has finished by `now` becomes `Completed`
+/// - A future fixture the source no longer lists is carried over as
+///   `Cancelled`, after the current fixtures
+///
+/// Statuses read from markup (postponed, cancelled, ...) are never overridden.
+pub fn reconcile(previous: &[Fixture], current: Vec<Fixture>, now: DateTime<Utc>) -> Vec<Fixture> {
+    let pairs = DiffEngine::new().pair(previous, &current, now);
+    let mut reconciled = Vec::with_capacity(current.len());
+
+    for (mut fixture, pair) in current.into_iter().zip(&pairs) {
+        if let Some(earlier) = pair.map(|i| &previous[i]) {
+            if fixture.status == FixtureStatus::Scheduled {
+                fixture.status = carried_status(earlier, &fixture);
+            }
+        }
+
+        if fixture.status.is_active() && fixture.expected_end() < now {
+            fixture.status = FixtureStatus::Completed;
+        }
+
+        reconciled.push(fixture);
+    }
+
+    // Dropped from the source before being played: keep it, marked cancelled
+    let unmatched = previous
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !pairs.contains(&Some(*i)));
+    for (_, dropped) in unmatched {
+        if dropped.datetime > now && dropped.status != FixtureStatus::Completed {
+            let mut cancelled = dropped.clone();
+            cancelled.status = FixtureStatus::Cancelled;
+            reconciled.push(cancelled);
+        }
+    }
+
+    reconciled
+}
+
+fn carried_status(earlier: &Fixture, current: &Fixture) -> FixtureStatus {
+    if earlier.datetime == current.datetime {
+        // Still at the rescheduled time, or unchanged
+        return match earlier.status {
+            FixtureStatus::Rescheduled { .. } => earlier.status,
+            _ => FixtureStatus::Scheduled,
+        };
+    }
+
+    let previous = match earlier.status {
+        FixtureStatus::Rescheduled { previous } => previous,
+        _ => earlier.datetime,
+    };
+    if previous == current.datetime {
+        // Moved back to where it started
+        FixtureStatus::Scheduled
+    } else {
+        FixtureStatus::Rescheduled { previous }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, utc};
+    use chrono::Duration;
+
+    fn fixture(opponent: &str, day: u32) -> Fixture {
+        test_support::fixture()
+            .opponent(opponent)
+            .at(utc(2025, 11, day, 15, 0))
+            .build()
+    }
+
+    #[test]
+    fn test_status_from_markup() {
+        assert_eq!(
+            FixtureStatus::from_label("P-P"),
+            Some(FixtureStatus::Postponed)
+        );
+        assert_eq!(
+            FixtureStatus::from_label("FT 2-1"),
+            Some(FixtureStatus::Completed)
+        );
+        assert_eq!(
+            FixtureStatus::from_label("Match Cancelled"),
+            Some(FixtureStatus::Cancelled)
+        );
+        assert_eq!(FixtureStatus::from_label("Sat 1 Nov - 15:00"), None);
+
+        assert_eq!(
+            FixtureStatus::from_markup("Match Postponed"),
+            Some(FixtureStatus::Postponed)
+        );
+        assert_eq!(
+            FixtureStatus::from_markup("CANCELLED"),
+            Some(FixtureStatus::Cancelled)
+        );
+        assert_eq!(
+            FixtureStatus::from_markup("Abandoned (waterlogged pitch)"),
+            Some(FixtureStatus::Abandoned)
+        );
+        // Words merely containing a status stay scheduled
+        assert_eq!(FixtureStatus::from_markup("Sheffield Utd"), None);
+        // Short codes elsewhere on a card could be part of a name
+        assert_eq!(
+            FixtureStatus::from_markup("Arsenal v Chelsea FT Lauderdale Cup"),
+            None
+        );
+        assert_eq!(FixtureStatus::from_markup("PP Arena, full time"), None);
+    }
+
+    #[test]
+    fn test_reconcile_reschedule_and_cancel() {
+        let now = utc(2025, 10, 20, 12, 0);
+        let previous = vec![
+            fixture("Burnley", 1),
+            fixture("Spurs", 8),
+            fixture("Fulham", 22),
+        ];
+
+        let mut moved = fixture("Spurs", 9);
+        moved.datetime += Duration::hours(1);
+        let current = vec![fixture("Burnley", 1), moved.clone()];
+
+        let reconciled = reconcile(&previous, current, now);
+        assert_eq!(reconciled.len(), 3);
+        assert_eq!(reconciled[0].status, FixtureStatus::Scheduled);
+        assert_eq!(
+            reconciled[1].status,
+            FixtureStatus::Rescheduled {
+                previous: previous[1].datetime
+            }
+        );
+        assert_eq!(reconciled[2].opponent, "Fulham");
+        assert_eq!(reconciled[2].status, FixtureStatus::Cancelled);
+
+        // Scraping again keeps the original kick-off on record
+        let again = reconcile(&reconciled, vec![moved], now);
+        assert_eq!(
+            again[0].status,
+            FixtureStatus::Rescheduled {
+                previous: previous[1].datetime
+            }
+        );
+    }
+
+    #[test]
+    fn test_reconcile_completed_and_markup_wins() {
+        let now = utc(2025, 11, 5, 12, 0);
+        let previous = vec![fixture("Burnley", 1), fixture("Spurs", 8)];
+
+        let postponed = fixture("Spurs", 8).with_status(FixtureStatus::Postponed);
+        let reconciled = reconcile(&previous, vec![fixture("Burnley", 1), postponed], now);
+
+        assert_eq!(reconciled[0].status, FixtureStatus::Completed);
+        assert_eq!(reconciled[1].status, FixtureStatus::Postponed);
+        assert_eq!(reconciled.len(), 2);
+    }
+}
@@ -0,0 +1,702 @@
+//! # Fixture Store
+//!
+//! Every scrape used to start from nothing. A [`FixtureStore`] keeps fixtures
+//! between runs so the CLI, the API and change detection share one source of
+//! truth:
+//!
+//! - **Upserts by identity**: each scraped fixture is paired with a stored one
+//!   the same way [`DiffEngine`] pairs scrapes, so a moved kick-off updates the
+//!   existing record instead of adding a second one
+//! - **History**: every distinct version is kept with the time it was scraped
+//! - **Queries**: by team, kick-off range, competition and status
+//!
+//! Two implementations ship: [`SqliteStore`], an embedded database in one
+//! file, and [`JsonDirStore`], one readable JSON file per fixture. [`open`]
+//! picks by path: `.db`, `.sqlite` and `.sqlite3` files are SQLite, anything
+//! else is a directory.
+
+use crate::changes::DiffEngine;
+use crate::dedupe::normalise_team;
+use crate::status::FixtureStatus;
+use crate::{Fixture, ScrapeError};
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A fixture as it was seen in one scrape.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FixtureVersion {
+    pub scraped_at: DateTime<Utc>,
+    pub fixture: Fixture,
+}
+
+/// The latest version of a stored fixture.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoredFixture {
+    /// Stable across reschedules, e.g. "arsenal-chelsea-20250816"
+    pub id: String,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub fixture: Fixture,
+}
+
+/// What an upsert did.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UpsertSummary {
+    pub inserted: usize,
+    /// Stored fixtures that changed, gaining a new version
+    pub updated: usize,
+    pub unchanged: usize,
+}
+
+/// Filters for [`FixtureStore::query`]; every filter left unset matches everything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FixtureQuery {
+    /// Team as displayed, e.g. "Arsenal Women"
+    pub team: Option<String>,
+    /// Kick-offs at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Kick-offs before this time
+    pub to: Option<DateTime<Utc>>,
+    /// Part of the competition name, e.g. "cup"
+    pub competition: Option<String>,
+    /// Matches any `Rescheduled` when given one
+    pub status: Option<FixtureStatus>,
+}
+
+impl FixtureQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_team(mut self, team: &str) -> Self {
+        self.team = Some(team.to_string());
+        self
+    }
+
+    pub fn between(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.from = Some(from);
+        self.to = Some(to);
+        self
+    }
+
+    pub fn with_competition(mut self, competition: &str) -> Self {
+        self.competition = Some(competition.to_string());
+        self
+    }
+
+    pub fn with_status(mut self, status: FixtureStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn matches(&self, fixture: &Fixture) -> bool {
+        self.team
+            .as_ref()
+            .is_none_or(|team| normalise_team(team) == normalise_team(&fixture.team_label()))
+            && self.from.is_none_or(|from| fixture.datetime >= from)
+            && self.to.is_none_or(|to| fixture.datetime < to)
+            && self
+                .competition
+                .as_ref()
+                .is_none_or(|competition| fixture.competition_matches(competition))
+            && self.status.is_none_or(|status| {
+                std::mem::discriminant(&status) == std::mem::discriminant(&fixture.status)
+            })
+    }
+}
+
+/// Persistent fixtures with version history.
+pub trait FixtureStore: Send {
+    /// Record one scrape taken at `scraped_at`.
+    fn upsert(
+        &mut self,
+        fixtures: &[Fixture],
+        scraped_at: DateTime<Utc>,
+    ) -> Result<UpsertSummary, ScrapeError>;
+
+    fn get(&self, id: &str) -> Result<Option<StoredFixture>, ScrapeError>;
+
+    /// Every distinct version of a fixture, oldest first.
+    fn history(&self, id: &str) -> Result<Vec<FixtureVersion>, ScrapeError>;
+
+    /// Matching fixtures in kick-off order.
+    fn query(&self, query: &FixtureQuery) -> Result<Vec<StoredFixture>, ScrapeError>;
+}
+
+/// Open the store at `path`: a SQLite file for `.db`/`.sqlite`/`.sqlite3`, otherwise a directory.
+pub fn open(path: &Path) -> Result<Box<dyn FixtureStore>, ScrapeError> {
+    let is_sqlite = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| matches!(extension, "db" | "sqlite" | "sqlite3"));
+
+    if is_sqlite {
+        Ok(Box::new(SqliteStore::open(path)?))
+    } else {
+        Ok(Box::new(JsonDirStore::open(path)?))
+    }
+}
+
+/// One step of an upsert, worked out the same way for every store.
+enum Upsert<'a> {
+    Insert { id: String, fixture: &'a Fixture },
+    Update { id: String, fixture: &'a Fixture },
+    Unchanged { id: String },
+}
+
+/// Pairs scraped fixtures with stored ones as [`DiffEngine::pair`] does, so a
+/// played fixture still on the page matches its record rather than adding one.
+fn plan_upsert<'a>(
+    stored: &[StoredFixture],
+    fixtures: &'a [Fixture],
+    scraped_at: DateTime<Utc>,
+) -> Vec<Upsert<'a>> {
+    let latest: Vec<Fixture> = stored.iter().map(|s| s.fixture.clone()).collect();
+    let pairs = DiffEngine::new().pair(&latest, fixtures, scraped_at);
+    let mut taken: HashSet<String> = stored.iter().map(|s| s.id.clone()).collect();
+
+    fixtures
+        .iter()
+        .zip(pairs)
+        .map(|(fixture, pair)| match pair.map(|i| &stored[i]) {
+            Some(existing) if existing.fixture == *fixture => Upsert::Unchanged {
+                id: existing.id.clone(),
+            },
+            Some(existing) => Upsert::Update {
+                id: existing.id.clone(),
+                fixture,
+            },
+            None => {
+                let id = new_id(fixture, &taken);
+                taken.insert(id.clone());
+                Upsert::Insert { id, fixture }
+            }
+        })
+        .collect()
+}
+
+/// Readable ID from the first sighting, e.g. "arsenal-women-chelsea-20250816".
+fn new_id(fixture: &Fixture, taken: &HashSet<String>) -> String {
+    let slug = |name: &str| normalise_team(name).replace(' ', "-");
+    let base = format!(
+        "{}-{}-{}",
+        slug(&fixture.team_label()),
+        slug(&fixture.opponent),
+        fixture.datetime.format("%Y%m%d")
+    );
+
+    (1..)
+        .map(|n| match n {
+            1 => base.clone(),
+            n => format!("{base}-{n}"),
+        })
+        .find(|id| !taken.contains(id))
+        .expect("unbounded range always finds a free ID")
+}
+
+fn storage_error(context: &str, e: impl fmt::Display) -> ScrapeError {
+    ScrapeError::Storage(format!("{context}: {e}"))
+}
+
+/// Fixtures in an embedded SQLite database.
+pub struct SqliteStore {
+    connection: Connection,
+}
+
+impl SqliteStore {
+    pub fn open(path: &Path) -> Result<Self, ScrapeError> {
+        let connection = Connection::open(path)
+            .map_err(|e| storage_error(&format!("Cannot open {}", path.display()), e))?;
+        Self::with_connection(connection)
+    }
+
+    /// A throwaway store, for tests and dry runs.
+    pub fn open_in_memory() -> Result<Self, ScrapeError> {
+        let connection =
+            Connection::open_in_memory().map_err(|e| storage_error("Cannot open database", e))?;
+        Self::with_connection(connection)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self, ScrapeError> {
+        connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS fixtures (
+                    id TEXT PRIMARY KEY,
+                    team TEXT NOT NULL,
+                    kick_off TEXT NOT NULL,
+                    status TEXT NOT NULL,
+                    first_seen TEXT NOT NULL,
+                    last_seen TEXT NOT NULL,
+                    fixture TEXT NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS fixture_versions (
+                    fixture_id TEXT NOT NULL REFERENCES fixtures(id),
+                    scraped_at TEXT NOT NULL,
+                    fixture TEXT NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS fixtures_by_kick_off ON fixtures (kick_off);
+                CREATE INDEX IF NOT EXISTS versions_by_fixture
+                    ON fixture_versions (fixture_id, scraped_at);",
+            )
+            .map_err(|e| storage_error("Cannot create schema", e))?;
+        Ok(Self { connection })
+    }
+}
+
+/// RFC 3339 in UTC with fixed precision, so text order is time order.
+fn timestamp(datetime: DateTime<Utc>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn parse_timestamp(text: &str) -> rusqlite::Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(text)
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+}
+
+fn parse_fixture(json: &str) -> rusqlite::Result<Fixture> {
+    serde_json::from_str(json).map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+}
+
+fn stored_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<StoredFixture> {
+    Ok(StoredFixture {
+        id: row.get(0)?,
+        first_seen: parse_timestamp(&row.get::<_, String>(1)?)?,
+        last_seen: parse_timestamp(&row.get::<_, String>(2)?)?,
+        fixture: parse_fixture(&row.get::<_, String>(3)?)?,
+    })
+}
+
+impl FixtureStore for SqliteStore {
+    fn upsert(
+        &mut self,
+        fixtures: &[Fixture],
+        scraped_at: DateTime<Utc>,
+    ) -> Result<UpsertSummary, ScrapeError> {
+        let stored = self.query(&FixtureQuery::default())?;
+        let plan = plan_upsert(&stored, fixtures, scraped_at);
+        let scraped_at = timestamp(scraped_at);
+        let context = "Cannot save fixtures";
+        let mut summary = UpsertSummary::default();
+
+        let transaction = self
+            .connection
+            .transaction()
+            .map_err(|e| storage_error(context, e))?;
+        for step in plan {
+            let (id, fixture) = match step {
+                Upsert::Unchanged { id } => {
+                    transaction
+                        .execute(
+                            "UPDATE fixtures SET last_seen = ?1 WHERE id = ?2",
+                            params![scraped_at, id],
+                        )
+                        .map_err(|e| storage_error(context, e))?;
+                    summary.unchanged += 1;
+                    continue;
+                }
+                Upsert::Insert { id, fixture } => {
+                    summary.inserted += 1;
+                    (id, fixture)
+                }
+                Upsert::Update { id, fixture } => {
+                    summary.updated += 1;
+                    (id, fixture)
+                }
+            };
+
+            let json = serde_json::to_string(fixture).map_err(|e| storage_error(context, e))?;
+            transaction
+                .execute(
+                    "INSERT INTO fixtures (id, team, kick_off, status, first_seen, last_seen, fixture)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?5, ?6)
+                     ON CONFLICT (id) DO UPDATE SET
+                        team = excluded.team,
+                        kick_off = excluded.kick_off,
+                        status = excluded.status,
+                        last_seen = excluded.last_seen,
+                        fixture = excluded.fixture",
+                    params![
+                        id,
+                        normalise_team(&fixture.team_label()),
+                        timestamp(fixture.datetime),
+                        fixture.status.to_string(),
+                        scraped_at,
+                        json
+                    ],
+                )
+                .map_err(|e| storage_error(context, e))?;
+            transaction
+                .execute(
+                    "INSERT INTO fixture_versions (fixture_id, scraped_at, fixture)
+                     VALUES (?1, ?2, ?3)",
+                    params![id, scraped_at, json],
+                )
+                .map_err(|e| storage_error(context, e))?;
+        }
+        transaction
+            .commit()
+            .map_err(|e| storage_error(context, e))?;
+
+        Ok(summary)
+    }
+
+    fn get(&self, id: &str) -> Result<Option<StoredFixture>, ScrapeError> {
+        self.connection
+            .query_row(
+                "SELECT id, first_seen, last_seen, fixture FROM fixtures WHERE id = ?1",
+                params![id],
+                stored_from_row,
+            )
+            .optional()
+            .map_err(|e| storage_error("Cannot read fixture", e))
+    }
+
+    fn history(&self, id: &str) -> Result<Vec<FixtureVersion>, ScrapeError> {
+        let context = "Cannot read fixture history";
+        let mut statement = self
+            .connection
+            .prepare(
+                "SELECT scraped_at, fixture FROM fixture_versions
+                 WHERE fixture_id = ?1 ORDER BY scraped_at, rowid",
+            )
+            .map_err(|e| storage_error(context, e))?;
+
+        let versions = statement
+            .query_map(params![id], |row| {
+                Ok(FixtureVersion {
+                    scraped_at: parse_timestamp(&row.get::<_, String>(0)?)?,
+                    fixture: parse_fixture(&row.get::<_, String>(1)?)?,
+                })
+            })
+            .map_err(|e| storage_error(context, e))?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(|e| storage_error(context, e))?;
+        Ok(versions)
+    }
+
+    fn query(&self, query: &FixtureQuery) -> Result<Vec<StoredFixture>, ScrapeError> {
+        let context = "Cannot query fixtures";
+
+        // Indexed columns narrow the scan; `matches` applies the rest
+        let mut conditions = vec!["1 = 1".to_string()];
+        let mut values = Vec::new();
+        if let Some(team) = &query.team {
+            values.push(normalise_team(team));
+            conditions.push(format!("team = ?{}", values.len()));
+        }
+        if let Some(from) = query.from {
+            values.push(timestamp(from));
+            conditions.push(format!("kick_off >= ?{}", values.len()));
+        }
+        if let Some(to) = query.to {
+            values.push(timestamp(to));
+            conditions.push(format!("kick_off < ?{}", values.len()));
+        }
+        if let Some(status) = query.status {
+            values.push(status.to_string());
+            conditions.push(format!("status = ?{}", values.len()));
+        }
+
+        let sql = format!(
+            "SELECT id, first_seen, last_seen, fixture FROM fixtures WHERE {} ORDER BY kick_off, id",
+            conditions.join(" AND ")
+        );
+        let mut statement = self
+            .connection
+            .prepare(&sql)
+            .map_err(|e| storage_error(context, e))?;
+        let stored = statement
+            .query_map(params_from_iter(values.iter()), stored_from_row)
+            .map_err(|e| storage_error(context, e))?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(|e| storage_error(context, e))?;
+
+        Ok(stored
+            .into_iter()
+            .filter(|stored| query.matches(&stored.fixture))
+            .collect())
+    }
+}
+
+/// Fixtures as one JSON file each in a directory, for reading and versioning by hand.
+pub struct JsonDirStore {
+    directory: PathBuf,
+}
+
+/// Contents of one file in a [`JsonDirStore`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct StoredRecord {
+    id: String,
+    first_seen: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+    /// Oldest first; never empty
+    versions: Vec<FixtureVersion>,
+}
+
+impl StoredRecord {
+    fn latest(&self) -> StoredFixture {
+        StoredFixture {
+            id: self.id.clone(),
+            first_seen: self.first_seen,
+            last_seen: self.last_seen,
+            fixture: self.versions[self.versions.len() - 1].fixture.clone(),
+        }
+    }
+}
+
+impl JsonDirStore {
+    /// Use `directory`, creating it if needed.
+    pub fn open(directory: &Path) -> Result<Self, ScrapeError> {
+        std::fs::create_dir_all(directory)
+            .map_err(|e| storage_error(&format!("Cannot create {}", directory.display()), e))?;
+        Ok(Self {
+            directory: directory.to_path_buf(),
+        })
+    }
+
+    fn path(&self, id: &str) -> PathBuf {
+        self.directory.join(format!("{id}.json"))
+    }
+
+    fn read(&self, path: &Path) -> Result<StoredRecord, ScrapeError> {
+        let context = "Cannot read stored fixture";
+        let json = std::fs::read_to_string(path).map_err(|e| storage_error(context, e))?;
+        serde_json::from_str(&json)
+            .map_err(|e| storage_error(context, format!("{}: {e}", path.display())))
+    }
+
+    fn write(&self, record: &StoredRecord) -> Result<(), ScrapeError> {
+        let context = "Cannot save fixture";
+        let json = serde_json::to_string_pretty(record).map_err(|e| storage_error(context, e))?;
+        std::fs::write(self.path(&record.id), json).map_err(|e| storage_error(context, e))
+    }
+
+    fn records(&self) -> Result<Vec<StoredRecord>, ScrapeError> {
+        let context = "Cannot list stored fixtures";
+        let mut records = Vec::new();
+
+        for entry in std::fs::read_dir(&self.directory).map_err(|e| storage_error(context, e))? {
+            let path = entry.map_err(|e| storage_error(context, e))?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                records.push(self.read(&path)?);
+            }
+        }
+
+        Ok(records)
+    }
+}
+
+impl FixtureStore for JsonDirStore {
+    fn upsert(
+        &mut self,
+        fixtures: &[Fixture],
+        scraped_at: DateTime<Utc>,
+    ) -> Result<UpsertSummary, ScrapeError> {
+        let stored = self.query(&FixtureQuery::default())?;
+        let mut summary = UpsertSummary::default();
+        let version = |fixture: &Fixture| FixtureVersion {
+            scraped_at,
+            fixture: fixture.clone(),
+        };
+
+        for step in plan_upsert(&stored, fixtures, scraped_at) {
+            let record = match step {
+                Upsert::Insert { id, fixture } => {
+                    summary.inserted += 1;
+                    StoredRecord {
+                        id,
+                        first_seen: scraped_at,
+                        last_seen: scraped_at,
+                        versions: vec![version(fixture)],
+                    }
+                }
+                Upsert::Update { id, fixture } => {
+                    summary.updated += 1;
+                    let mut record = self.read(&self.path(&id))?;
+                    record.last_seen = scraped_at;
+                    record.versions.push(version(fixture));
+                    record
+                }
+                Upsert::Unchanged { id } => {
+                    summary.unchanged += 1;
+                    let mut record = self.read(&self.path(&id))?;
+                    record.last_seen = scraped_at;
+                    record
+                }
+            };
+            self.write(&record)?;
+        }
+
+        Ok(summary)
+    }
+
+    fn get(&self, id: &str) -> Result<Option<StoredFixture>, ScrapeError> {
+        let path = self.path(id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(self.read(&path)?.latest()))
+    }
+
+    fn history(&self, id: &str) -> Result<Vec<FixtureVersion>, ScrapeError> {
+        let path = self.path(id);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        Ok(self.read(&path)?.versions)
+    }
+
+    fn query(&self, query: &FixtureQuery) -> Result<Vec<StoredFixture>, ScrapeError> {
+        let mut stored: Vec<StoredFixture> = self
+            .records()?
+            .iter()
+            .map(StoredRecord::latest)
+            .filter(|stored| query.matches(&stored.fixture))
+            .collect();
+        stored.sort_by(|a, b| {
+            a.fixture
+                .datetime
+                .cmp(&b.fixture.datetime)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        Ok(stored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, utc};
+    use chrono::Duration;
+
+    fn fixture(opponent: &str, competition: &str, day: u32) -> Fixture {
+        test_support::fixture()
+            .opponent(opponent)
+            .competition(competition)
+            .at(utc(2025, 10, day, 14, 0))
+            .build()
+    }
+
+    fn scraped_at(day: u32) -> DateTime<Utc> {
+        utc(2025, 9, day, 8, 0)
+    }
+
+    /// Runs the same scenario against any store.
+    fn exercise(store: &mut dyn FixtureStore) {
+        let first = vec![
+            fixture("Fulham", "Premier League", 4),
+            fixture("TBD Opponent", "Carabao Cup", 29),
+        ];
+        let summary = store.upsert(&first, scraped_at(1)).unwrap();
+        assert_eq!(summary.inserted, 2);
+
+        // Sky moves the Fulham game; the cup draw fills in the opponent
+        let mut moved = fixture("Fulham", "Premier League", 5);
+        moved.datetime += Duration::hours(2);
+        let second = vec![moved.clone(), fixture("Brighton", "Carabao Cup", 29)];
+        let summary = store.upsert(&second, scraped_at(8)).unwrap();
+        assert_eq!(
+            summary,
+            UpsertSummary {
+                inserted: 0,
+                updated: 2,
+                unchanged: 0
+            }
+        );
+
+        let summary = store.upsert(&second, scraped_at(15)).unwrap();
+        assert_eq!(summary.unchanged, 2);
+
+        let id = "arsenal-fulham-20251004";
+        let stored = store
+            .get(id)
+            .unwrap()
+            .expect("ID is stable across the move");
+        assert_eq!(stored.fixture, moved);
+        assert_eq!(stored.first_seen, scraped_at(1));
+        assert_eq!(stored.last_seen, scraped_at(15));
+
+        let history = store.history(id).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].fixture.datetime, first[0].datetime);
+        assert_eq!(history[1].scraped_at, scraped_at(8));
+
+        let cup = store
+            .query(&FixtureQuery::new().with_competition("carabao"))
+            .unwrap();
+        assert_eq!(cup.len(), 1);
+        assert_eq!(cup[0].id, "arsenal-tbd-opponent-20251029");
+        assert_eq!(cup[0].fixture.opponent, "Brighton");
+
+        let october = FixtureQuery::new().between(utc(2025, 10, 1, 0, 0), utc(2025, 10, 15, 0, 0));
+        assert_eq!(
+            store
+                .query(&october.clone().with_team("arsenal"))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(store
+            .query(&october.with_team("Arsenal Women"))
+            .unwrap()
+            .is_empty());
+        assert_eq!(store.query(&FixtureQuery::new()).unwrap()[0].id, id);
+
+        // Status filters match any rescheduled fixture
+        let rescheduled = moved.with_status(FixtureStatus::Rescheduled {
+            previous: first[0].datetime,
+        });
+        store.upsert(&[rescheduled], scraped_at(22)).unwrap();
+        let any_rescheduled = FixtureQuery::new().with_status(FixtureStatus::Rescheduled {
+            previous: scraped_at(1),
+        });
+        assert_eq!(store.query(&any_rescheduled).unwrap()[0].id, id);
+        assert!(store.get("missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_sqlite_store() {
+        exercise(&mut SqliteStore::open_in_memory().unwrap());
+    }
+
+    #[test]
+    fn test_json_dir_store() {
+        let directory = std::env::temp_dir().join(format!("calpal-store-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+
+        exercise(&mut JsonDirStore::open(&directory).unwrap());
+        assert!(directory.join("arsenal-fulham-20251004.json").exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_new_ids_are_unique() {
+        let fixture = fixture("Chelsea", "Premier League", 4);
+        let mut taken = HashSet::new();
+        taken.insert(new_id(&fixture, &taken));
+        assert_eq!(new_id(&fixture, &taken), "arsenal-chelsea-20251004-2");
+    }
+
+    #[test]
+    fn test_played_fixture_stays_unchanged() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let played = vec![fixture("Chelsea", "Premier League", 4)];
+        store.upsert(&played, scraped_at(20)).unwrap();
+
+        // Re-scraped long after the final whistle, it's the same record
+        let later = utc(2025, 10, 20, 8, 0);
+        let summary = store.upsert(&played, later).unwrap();
+        assert_eq!(summary.unchanged, 1);
+        assert_eq!(summary.inserted, 0);
+        assert_eq!(store.query(&FixtureQuery::new()).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,739 @@
+//! # Event Templates
+//!
+//! Calendar event titles, descriptions and locations are rendered from an
+//! [`EventTemplate`] rather than a hard-coded format, so a group whose
+//! calendar client mangles emoji, or who just want "ARS v CHE (PL)", can pick
+//! another layout. Three presets ship with the library:
+//!
+//! - `verbose`: the full title and description with emoji headers (the default)
+//! - `terse`: "ARS v CHE (PL)" with kick-off and venue underneath
+//! - `plain`: `verbose` without emoji, transliterated to ASCII
+//!
+//! Custom templates are JSON; fields left out fall back to `verbose`:
+//!
+//! ```json
+//! {
+//!   "title": "{team_short} v {opponent_short}{?status_tag} [{status_tag}]{/status_tag}",
+//!   "description": "{competition}, {kick_off}{?issues}\n{issues}{/issues}",
+//!   "ascii": true
+//! }
+//! ```
+//!
+//! ## Syntax
+//!
+//! `{name}` inserts a variable, `{?name}...{/name}` keeps its contents only
+//! when the variable isn't empty, and `{{`/`}}` are literal braces. Unknown
+//! variables are rejected when the template is parsed.
+//!
+//! ## Variables
+//!
+//! Fixture: `title`, `team`, `team_short`, `opponent`, `opponent_short`,
+//! `competition`, `competition_short`, `venue`, `location` (empty while the
+//! venue is a placeholder), `kick_off`, `date`, `time`, `kick_off_utc`, `end`,
+//! `sport`, `squad`, `sport_details`, `duration` (minutes), `extra_time`
+//! (minutes, empty when it can't go to extra time), `status`, `status_tag`
+//! ("POSTPONED" when called off), `status_note`, `sources`, `url`.
+//!
+//! Validation: `validation`, `issue_count`, `issues`, `warnings`, `errors`
+//! (bullet lists), `scraped` (date, historical fixtures only), `confidence`.
+//!
+//! Parse metadata: `original_source`, `parsing_strategy`,
+//! `timezone_assumptions`, `locale`, `date_format`.
+//!
+//! Times are local to the timezone passed to [`EventTemplate::render`].
+
+use crate::confidence::{placeholder_fields, FixtureField};
+use crate::dedupe::normalise_team;
+use crate::status::FixtureStatus;
+use crate::validation::{FixtureValidation, ValidatedFixture, ValidationIssue};
+use crate::ScrapeError;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Names accepted by [`EventTemplate::preset`].
+pub const PRESETS: [&str; 3] = ["verbose", "terse", "plain"];
+
+/// Every variable a template may use; see the module docs.
+pub const VARIABLES: [&str; 36] = [
+    "title",
+    "team",
+    "team_short",
+    "opponent",
+    "opponent_short",
+    "competition",
+    "competition_short",
+    "venue",
+    "location",
+    "kick_off",
+    "date",
+    "time",
+    "kick_off_utc",
+    "end",
+    "sport",
+    "squad",
+    "sport_details",
+    "duration",
+    "extra_time",
+    "status",
+    "status_tag",
+    "status_note",
+    "sources",
+    "url",
+    "validation",
+    "issue_count",
+    "issues",
+    "warnings",
+    "errors",
+    "scraped",
+    "confidence",
+    "original_source",
+    "parsing_strategy",
+    "timezone_assumptions",
+    "locale",
+    "date_format",
+];
+
+const KICK_OFF_FORMAT: &str = "%a %d %b %H:%M";
+
+const VERBOSE_TITLE: &str = "{?status_tag}[{status_tag}] {/status_tag}{title}";
+const VERBOSE_DESCRIPTION: &str = "{title} at {venue}\nCompetition: {competition}\
+    {?sport_details}\n{sport}: {sport_details}{/sport_details}\
+    {?status_note}\n{status_note}{/status_note}\
+    {?extra_time}\nMay go to extra time{/extra_time}\
+    {?warnings}\n\n⚠️ Data Quality Notes:\n{warnings}{/warnings}\
+    {?errors}\n\n❌ Data Issues Detected:\n{errors}{/errors}\
+    {?scraped}\n\n📅 Historical fixture (scraped {scraped}){/scraped}";
+const PLAIN_DESCRIPTION: &str = "{title} at {venue}\nCompetition: {competition}\
+    \nKick-off: {kick_off}\
+    {?sport_details}\n{sport}: {sport_details}{/sport_details}\
+    {?status_note}\n{status_note}{/status_note}\
+    {?extra_time}\nMay go to extra time{/extra_time}\
+    {?warnings}\n\nData quality notes:\n{warnings}{/warnings}\
+    {?errors}\n\nData issues detected:\n{errors}{/errors}\
+    {?scraped}\n\nHistorical fixture (scraped {scraped}){/scraped}";
+const TERSE_TITLE: &str =
+    "{?status_tag}[{status_tag}] {/status_tag}{team_short} v {opponent_short} ({competition_short})";
+const TERSE_DESCRIPTION: &str =
+    "{kick_off}{?location}, {location}{/location}{?status_note}\n{status_note}{/status_note}";
+const LOCATION: &str = "{location}";
+
+/// A parsed `{variable}` template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    source: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    Variable(String),
+    /// Rendered only when the variable isn't empty
+    Section(String, Vec<Segment>),
+}
+
+/// Title, description and location templates for calendar events.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "EventTemplateConfig", into = "EventTemplateConfig")]
+pub struct EventTemplate {
+    pub title: Template,
+    pub description: Template,
+    pub location: Template,
+    /// Transliterate output to ASCII and drop anything else (emoji)
+    pub ascii: bool,
+}
+
+/// The on-disk form of an [`EventTemplate`]; unset fields come from `verbose`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct EventTemplateConfig {
+    title: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    ascii: bool,
+}
+
+/// An event's rendered text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedEvent {
+    pub title: String,
+    pub description: String,
+    /// Empty when there is nothing worth showing as a location
+    pub location: String,
+}
+
+impl Template {
+    /// Parse an event template, which may use any of [`VARIABLES`].
+    pub fn parse(source: &str) -> Result<Self, ScrapeError> {
+        Self::parse_with_variables(source, &VARIABLES)
+    }
+
+    /// Parse a template for another context, accepting only `variables`.
+    pub fn parse_with_variables(source: &str, variables: &[&str]) -> Result<Self, ScrapeError> {
+        let mut chars = source.chars();
+        let segments = parse_segments(&mut chars, None, variables)?;
+        Ok(Self {
+            source: source.to_string(),
+            segments,
+        })
+    }
+
+    /// The text the template was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn render(&self, variables: &HashMap<&'static str, String>) -> String {
+        let mut output = String::new();
+        render_segments(&self.segments, variables, &mut output);
+        output
+    }
+}
+
+impl Default for EventTemplate {
+    fn default() -> Self {
+        Self::verbose()
+    }
+}
+
+impl EventTemplate {
+    /// Build from template strings; every one must parse.
+    pub fn new(title: &str, description: &str, location: &str) -> Result<Self, ScrapeError> {
+        Ok(Self {
+            title: Template::parse(title)?,
+            description: Template::parse(description)?,
+            location: Template::parse(location)?,
+            ascii: false,
+        })
+    }
+
+    pub fn with_ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Full title and description with emoji headers.
+    pub fn verbose() -> Self {
+        Self::new(VERBOSE_TITLE, VERBOSE_DESCRIPTION, LOCATION).expect("verbose preset parses")
+    }
+
+    /// "ARS v CHE (PL)" with the kick-off and venue as the description.
+    pub fn terse() -> Self {
+        Self::new(TERSE_TITLE, TERSE_DESCRIPTION, LOCATION).expect("terse preset parses")
+    }
+
+    /// `verbose` without emoji, in plain ASCII.
+    pub fn plain() -> Self {
+        Self::new(VERBOSE_TITLE, PLAIN_DESCRIPTION, LOCATION)
+            .expect("plain preset parses")
+            .with_ascii(true)
+    }
+
+    /// A preset by name (see [`PRESETS`]).
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "verbose" => Some(Self::verbose()),
+            "terse" => Some(Self::terse()),
+            "plain" => Some(Self::plain()),
+            _ => None,
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ScrapeError> {
+        serde_json::from_str(json)
+            .map_err(|e| ScrapeError::Parse(format!("Invalid event template: {e}")))
+    }
+
+    /// Read a custom template from a JSON file.
+    pub fn load(path: &Path) -> Result<Self, ScrapeError> {
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            ScrapeError::Parse(format!("Cannot read template {}: {e}", path.display()))
+        })?;
+        Self::from_json(&json)
+    }
+
+    /// Render every field for `validated`, with times in `timezone`.
+    pub fn render(&self, validated: &ValidatedFixture, timezone: Tz) -> RenderedEvent {
+        let variables = variables(validated, timezone);
+        let finish = |template: &Template| {
+            let text = template.render(&variables);
+            if self.ascii {
+                to_ascii(&text)
+            } else {
+                text
+            }
+        };
+
+        RenderedEvent {
+            title: finish(&self.title),
+            description: finish(&self.description),
+            location: finish(&self.location),
+        }
+    }
+}
+
+impl TryFrom<EventTemplateConfig> for EventTemplate {
+    type Error = ScrapeError;
+
+    fn try_from(config: EventTemplateConfig) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            config.title.as_deref().unwrap_or(VERBOSE_TITLE),
+            config.description.as_deref().unwrap_or(VERBOSE_DESCRIPTION),
+            config.location.as_deref().unwrap_or(LOCATION),
+        )?
+        .with_ascii(config.ascii))
+    }
+}
+
+impl From<EventTemplate> for EventTemplateConfig {
+    fn from(template: EventTemplate) -> Self {
+        Self {
+            title: Some(template.title.source),
+            description: Some(template.description.source),
+            location: Some(template.location.source),
+            ascii: template.ascii,
+        }
+    }
+}
+
+/// Every variable's value for `validated`, with times in `timezone`.
+pub fn variables(validated: &ValidatedFixture, timezone: Tz) -> HashMap<&'static str, String> {
+    let fixture = &validated.fixture;
+    let metadata = &fixture.parse_metadata;
+    let local = |datetime: DateTime<Utc>| datetime.with_timezone(&timezone);
+    let kick_off = local(fixture.datetime);
+    let duration = fixture.duration();
+
+    let issues = validated.validation.issues();
+    let (warnings, errors, scraped) = match &validated.validation {
+        FixtureValidation::Valid => (String::new(), String::new(), String::new()),
+        FixtureValidation::ValidWithWarnings(issues) => {
+            (bullets(issues), String::new(), String::new())
+        }
+        FixtureValidation::Invalid(issues) => (String::new(), bullets(issues), String::new()),
+        FixtureValidation::Historical(scraped_at) => (
+            String::new(),
+            String::new(),
+            scraped_at.format("%Y-%m-%d").to_string(),
+        ),
+    };
+
+    let (status_tag, status_note) = match fixture.status {
+        FixtureStatus::Rescheduled { previous } => (
+            String::new(),
+            format!(
+                "Rescheduled (was {})",
+                local(previous).format(KICK_OFF_FORMAT)
+            ),
+        ),
+        status if status.is_called_off() => {
+            let tag = status.to_string().to_uppercase();
+            (tag.clone(), format!("Status: {tag}"))
+        }
+        _ => (String::new(), String::new()),
+    };
+    let location = if placeholder_fields(fixture).contains(&FixtureField::Venue) {
+        String::new()
+    } else {
+        fixture.venue.clone()
+    };
+    let team_short = match fixture.squad.team_suffix() {
+        Some(suffix) => format!("{} {}", short_team(&fixture.team), &suffix[..1]),
+        None => short_team(&fixture.team),
+    };
+    let optional = |value: Option<String>| value.unwrap_or_default();
+
+    HashMap::from([
+        ("title", fixture.title()),
+        ("team", fixture.team_label()),
+        ("team_short", team_short),
+        ("opponent", fixture.opponent.clone()),
+        ("opponent_short", short_team(&fixture.opponent)),
+        ("competition", fixture.competition.clone()),
+        ("competition_short", short_competition(&fixture.competition)),
+        ("venue", fixture.venue.clone()),
+        ("location", location),
+        ("kick_off", kick_off.format(KICK_OFF_FORMAT).to_string()),
+        ("date", kick_off.format("%a %d %b %Y").to_string()),
+        ("time", kick_off.format("%H:%M").to_string()),
+        ("kick_off_utc", fixture.datetime.to_rfc3339()),
+        (
+            "end",
+            local(fixture.expected_end()).format("%H:%M").to_string(),
+        ),
+        ("sport", fixture.sport.to_string()),
+        ("squad", fixture.squad.to_string()),
+        (
+            "sport_details",
+            optional(fixture.sport_details.as_ref().map(ToString::to_string)),
+        ),
+        ("duration", duration.minutes.to_string()),
+        (
+            "extra_time",
+            optional(
+                duration
+                    .may_go_to_extra_time()
+                    .then(|| duration.extra_time_minutes.to_string()),
+            ),
+        ),
+        ("status", fixture.status.to_string()),
+        ("status_tag", status_tag),
+        ("status_note", status_note),
+        (
+            "sources",
+            fixture
+                .sources
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        (
+            "url",
+            optional(fixture.sources.first().map(|source| source.url.clone())),
+        ),
+        ("validation", validated.validation.label().to_string()),
+        ("issue_count", issues.len().to_string()),
+        ("issues", bullets(issues)),
+        ("warnings", warnings),
+        ("errors", errors),
+        ("scraped", scraped),
+        ("confidence", validated.confidence.to_string()),
+        ("original_source", metadata.original_source.clone()),
+        ("parsing_strategy", metadata.parsing_strategy.to_string()),
+        (
+            "timezone_assumptions",
+            metadata.timezone_assumptions.clone(),
+        ),
+        (
+            "locale",
+            optional(metadata.locale.as_ref().map(ToString::to_string)),
+        ),
+        (
+            "date_format",
+            optional(
+                metadata
+                    .date_format
+                    .as_ref()
+                    .map(|format| format.family.to_string()),
+            ),
+        ),
+    ])
+}
+
+/// Three-letter code for a team ("Arsenal" → "ARS", "Nottingham Forest" → "NFO").
+pub fn short_team(name: &str) -> String {
+    let normalised = normalise_team(name);
+    let known = match normalised.as_str() {
+        "aston villa" => Some("AVL"),
+        "brighton and hove albion" | "brighton" => Some("BHA"),
+        "crystal palace" => Some("CRY"),
+        "manchester city" | "man city" => Some("MCI"),
+        "manchester united" | "man utd" | "man united" => Some("MUN"),
+        "nottingham forest" => Some("NFO"),
+        "tottenham hotspur" | "tottenham" | "spurs" => Some("TOT"),
+        "west ham united" | "west ham" => Some("WHU"),
+        "wolverhampton wanderers" | "wolves" => Some("WOL"),
+        "new zealand" => Some("NZL"),
+        "south africa" => Some("RSA"),
+        _ => None,
+    };
+
+    match known {
+        Some(code) => code.to_string(),
+        None => normalised
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(3)
+            .collect::<String>()
+            .to_uppercase(),
+    }
+}
+
+/// Initials for a competition ("Premier League" → "PL"), with the usual
+/// codes for the European cups.
+pub fn short_competition(name: &str) -> String {
+    let known = match normalise_team(name).as_str() {
+        "uefa champions league" | "champions league" => Some("UCL"),
+        "uefa europa league" | "europa league" => Some("UEL"),
+        "uefa conference league" | "conference league" => Some("UECL"),
+        "fa cup" | "emirates fa cup" => Some("FAC"),
+        "carabao cup" | "league cup" | "efl cup" => Some("EFL"),
+        _ => None,
+    };
+    if let Some(code) = known {
+        return code.to_string();
+    }
+
+    let words: Vec<&str> = name
+        .split_whitespace()
+        .filter(|word| !matches!(word.to_lowercase().as_str(), "the" | "of" | "and"))
+        .collect();
+    match words.as_slice() {
+        [single] => single.chars().take(3).collect::<String>().to_uppercase(),
+        words => words
+            .iter()
+            .filter_map(|word| word.chars().find(|c| c.is_alphanumeric()))
+            .collect::<String>()
+            .to_uppercase(),
+    }
+}
+
+/// Transliterate accented letters and typographic punctuation; drop
+/// anything else outside ASCII.
+pub fn to_ascii(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() {
+            output.push(c);
+            continue;
+        }
+        let replacement = match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => "a",
+            'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => "A",
+            'æ' => "ae",
+            'Æ' => "AE",
+            'ç' | 'ć' | 'č' => "c",
+            'Ç' | 'Ć' | 'Č' => "C",
+            'è' | 'é' | 'ê' | 'ë' => "e",
+            'È' | 'É' | 'Ê' | 'Ë' => "E",
+            'ì' | 'í' | 'î' | 'ï' => "i",
+            'Ì' | 'Í' | 'Î' | 'Ï' => "I",
+            'ñ' => "n",
+            'Ñ' => "N",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => "o",
+            'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' => "O",
+            'š' => "s",
+            'Š' => "S",
+            'ß' => "ss",
+            'ù' | 'ú' | 'û' | 'ü' => "u",
+            'Ù' | 'Ú' | 'Û' | 'Ü' => "U",
+            'ý' | 'ÿ' => "y",
+            'Ý' => "Y",
+            'ž' => "z",
+            'Ž' => "Z",
+            '‘' | '’' => "'",
+            '“' | '”' => "\"",
+            '–' | '—' | '•' => "-",
+            '→' => "->",
+            '…' => "...",
+            '\u{a0}' => " ",
+            _ => "",
+        };
+        output.push_str(replacement);
+    }
+    output
+}
+
+fn bullets(issues: &[ValidationIssue]) -> String {
+    issues
+        .iter()
+        .map(|issue| format!("• {}", issue.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parse until the end of input, or the `{/name}` closing `open`.
+fn parse_segments(
+    chars: &mut std::str::Chars<'_>,
+    open: Option<&str>,
+    known: &[&str],
+) -> Result<Vec<Segment>, ScrapeError> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                text.push('}');
+            }
+            '}' => {
+                return Err(ScrapeError::Parse(
+                    "Unmatched '}' in template; write '}}' for a literal brace".to_string(),
+                ))
+            }
+            '{' => {
+                let Some(end) = chars.as_str().find('}') else {
+                    return Err(ScrapeError::Parse("Unclosed '{' in template".to_string()));
+                };
+                let tag = chars.as_str()[..end].to_string();
+                chars.nth(end);
+
+                if !text.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                }
+                if let Some(name) = tag.strip_prefix('?') {
+                    check_variable(name, known)?;
+                    let body = parse_segments(chars, Some(name), known)?;
+                    segments.push(Segment::Section(name.to_string(), body));
+                } else if let Some(name) = tag.strip_prefix('/') {
+                    if open == Some(name) {
+                        return Ok(segments);
+                    }
+                    return Err(ScrapeError::Parse(format!(
+                        "Template closes {{/{name}}} without a matching {{?{name}}}"
+                    )));
+                } else {
+                    check_variable(&tag, known)?;
+                    segments.push(Segment::Variable(tag));
+                }
+            }
+            c => text.push(c),
+        }
+    }
+
+    if let Some(name) = open {
+        return Err(ScrapeError::Parse(format!(
+            "Template section {{?{name}}} is never closed"
+        )));
+    }
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+fn check_variable(name: &str, known: &[&str]) -> Result<(), ScrapeError> {
+    if known.contains(&name) {
+        Ok(())
+    } else {
+        Err(ScrapeError::Parse(format!(
+            "Unknown template variable {{{name}}}"
+        )))
+    }
+}
+
+fn render_segments(
+    segments: &[Segment],
+    variables: &HashMap<&'static str, String>,
+    output: &mut String,
+) {
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => output.push_str(text),
+            Segment::Variable(name) => {
+                output.push_str(variables.get(name.as_str()).map_or("", String::as_str))
+            }
+            Segment::Section(name, body) => {
+                if variables
+                    .get(name.as_str())
+                    .is_some_and(|value| !value.is_empty())
+                {
+                    render_segments(body, variables, output);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{fixture, validated};
+    use crate::validation::{IssueCategory, IssueSeverity};
+    use crate::Squad;
+    use chrono_tz::Europe::London;
+
+    fn with_warning(mut validated: ValidatedFixture) -> ValidatedFixture {
+        validated.validation = FixtureValidation::ValidWithWarnings(vec![ValidationIssue {
+            severity: IssueSeverity::Warning,
+            category: IssueCategory::SuspiciousTime,
+            message: "Kick-off at 03:00 is unusual".to_string(),
+            suggested_fix: None,
+        }]);
+        validated
+    }
+
+    #[test]
+    fn test_presets() {
+        let event = EventTemplate::terse().render(&fixture().validated(), London);
+        assert_eq!(event.title, "ARS v CHE (PL)");
+        assert_eq!(event.description, "Sat 16 Aug 15:00, Emirates Stadium");
+        assert_eq!(event.location, "Emirates Stadium");
+
+        let postponed = fixture()
+            .build()
+            .with_status(FixtureStatus::Postponed)
+            .with_squad(Squad::Women);
+        let event = EventTemplate::terse().render(&validated(postponed.clone()), London);
+        assert_eq!(event.title, "[POSTPONED] ARS W v CHE (PL)");
+
+        let event = EventTemplate::verbose().render(&validated(postponed), London);
+        assert_eq!(event.title, "[POSTPONED] Arsenal Women vs Chelsea");
+
+        for name in PRESETS {
+            assert!(EventTemplate::preset(name).is_some());
+        }
+        assert!(EventTemplate::preset("fancy").is_none());
+    }
+
+    #[test]
+    fn test_verbose_matches_calendar_description() {
+        let validated = with_warning(fixture().validated());
+        let event = EventTemplate::verbose().render(&validated, London);
+
+        assert_eq!(event.description, validated.get_calendar_description());
+        assert!(event
+            .description
+            .ends_with("\n\n⚠️ Data Quality Notes:\n• Kick-off at 03:00 is unusual"));
+    }
+
+    #[test]
+    fn test_plain_is_ascii() {
+        let validated = with_warning(fixture().venue("Estádio José Alvalade").validated());
+        let event = EventTemplate::plain().render(&validated, London);
+
+        assert!(event.description.is_ascii());
+        assert_eq!(event.location, "Estadio Jose Alvalade");
+        assert!(event
+            .description
+            .contains("\nKick-off: Sat 16 Aug 15:00\n\nData quality notes:\n- Kick-off"));
+    }
+
+    #[test]
+    fn test_syntax() {
+        let variables = HashMap::from([("team", "Arsenal".to_string()), ("issues", String::new())]);
+        let template = Template::parse("{{{team}}}{?issues} with issues{/issues}!").unwrap();
+        assert_eq!(template.render(&variables), "{Arsenal}!");
+
+        for bad in ["{nickname}", "{?issues}open", "{/issues}", "{team", "team}"] {
+            assert!(Template::parse(bad).is_err(), "{bad} should not parse");
+        }
+    }
+
+    #[test]
+    fn test_custom_template_from_json() {
+        let template = EventTemplate::from_json(
+            r#"{ "title": "{team_short} v {opponent_short}", "ascii": true }"#,
+        )
+        .unwrap();
+        assert_eq!(template.description, EventTemplate::verbose().description);
+
+        let event = template.render(&fixture().validated(), London);
+        assert_eq!(event.title, "ARS v CHE");
+        assert!(!event.description.contains('⚠'));
+
+        let error = EventTemplate::from_json(r#"{ "title": "{nickname}" }"#).unwrap_err();
+        assert!(error.to_string().contains("{nickname}"));
+    }
+
+    #[test]
+    fn test_short_names() {
+        assert_eq!(short_team("Nottingham Forest"), "NFO");
+        assert_eq!(short_team("Brentford FC"), "BRE");
+        assert_eq!(short_team("TBD Opponent"), "TBD");
+        assert_eq!(short_competition("Women's Super League"), "WSL");
+        assert_eq!(short_competition("UEFA Champions League"), "UCL");
+        assert_eq!(short_competition("Friendly"), "FRI");
+    }
+}
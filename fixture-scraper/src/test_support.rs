@@ -0,0 +1,99 @@
+//! Shared fixtures for unit tests.
+//!
+//! Every module's tests start from the same match, Arsenal vs Chelsea in the
+//! Premier League at the Emirates on Sat 16 Aug 2025 (15:00 London), seen by
+//! a clock fixed a fortnight earlier. Tests change only what they're about:
+//!
+//! ```ignore
+//! let derby = fixture().opponent("Tottenham Hotspur").at(utc(2025, 11, 22, 17, 30));
+//! let validated = derby.validated();
+//! ```
+
+use crate::clock::FixedClock;
+use crate::parsing::ParseMetadata;
+use crate::sport::Sport;
+use crate::validation::ValidatedFixture;
+use crate::{Fixture, FixtureSource};
+use chrono::{DateTime, TimeZone, Utc};
+
+/// The shared "current time": Fri 1 Aug 2025, 12:00 UTC.
+pub(crate) fn now() -> DateTime<Utc> {
+    utc(2025, 8, 1, 12, 0)
+}
+
+/// A clock fixed at [`now`].
+pub(crate) fn clock() -> FixedClock {
+    FixedClock::new(now())
+}
+
+pub(crate) fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .unwrap()
+}
+
+/// `fixture` validated by the standard rules as of [`now`].
+pub(crate) fn validated(fixture: Fixture) -> ValidatedFixture {
+    ValidatedFixture::with_clock(fixture, &clock())
+}
+
+/// Arsenal vs Chelsea, to be adjusted with the builder methods.
+pub(crate) fn fixture() -> FixtureBuilder {
+    FixtureBuilder(Fixture::new(
+        "Arsenal".to_string(),
+        "Chelsea".to_string(),
+        utc(2025, 8, 16, 14, 0),
+        "Emirates Stadium".to_string(),
+        "Premier League".to_string(),
+        ParseMetadata::default(),
+    ))
+}
+
+pub(crate) struct FixtureBuilder(Fixture);
+
+impl FixtureBuilder {
+    pub(crate) fn team(mut self, team: &str) -> Self {
+        self.0.team = team.to_string();
+        self
+    }
+
+    pub(crate) fn opponent(mut self, opponent: &str) -> Self {
+        self.0.opponent = opponent.to_string();
+        self
+    }
+
+    pub(crate) fn venue(mut self, venue: &str) -> Self {
+        self.0.venue = venue.to_string();
+        self
+    }
+
+    pub(crate) fn competition(mut self, competition: &str) -> Self {
+        self.0.competition = competition.to_string();
+        self
+    }
+
+    /// Kick-off in UTC.
+    pub(crate) fn at(mut self, datetime: DateTime<Utc>) -> Self {
+        self.0.datetime = datetime;
+        self
+    }
+
+    pub(crate) fn sport(mut self, sport: Sport) -> Self {
+        self.0.sport = sport;
+        self
+    }
+
+    /// Listed on the club's men's fixture page.
+    pub(crate) fn on_arsenal_page(self) -> Self {
+        let source = FixtureSource::new("Arsenal", "https://www.arsenal.com/fixtures");
+        Self(self.0.with_source(source))
+    }
+
+    pub(crate) fn build(self) -> Fixture {
+        self.0
+    }
+
+    /// Validated by the standard rules as of [`now`].
+    pub(crate) fn validated(self) -> ValidatedFixture {
+        validated(self.0)
+    }
+}
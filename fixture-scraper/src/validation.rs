@@ -19,9 +19,17 @@
 //! - Quality warnings become calendar event descriptions
 //! - Rich metadata from parsing feeds into validation decisions
 //! - London timezone focus for display
+//! - Parse confidence below configurable thresholds becomes an issue of matching severity
+//!
+//! The checks themselves live in [`crate::rules`]; `FixtureValidator` runs the
+//! standard rule set.
 
+use crate::clock::{Clock, SystemClock};
+use crate::confidence::{ConfidenceThresholds, ParseConfidence};
+use crate::rules::{ConfidenceRule, RuleSet};
+use crate::templates::EventTemplate;
 use crate::Fixture;
-use chrono::{DateTime, Datelike, Timelike, Utc, Weekday};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -29,6 +37,8 @@ use std::fmt;
 pub struct ValidatedFixture {
     pub fixture: Fixture,
     pub validation: FixtureValidation,
+    #[serde(default)]
+    pub confidence: ParseConfidence,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -47,248 +57,142 @@ pub struct ValidationIssue {
     pub suggested_fix: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum IssueSeverity {
     Warning,  // Keep fixture, but note the issue
     Error,    // Fixture is problematic but might be usable
     Critical, // Fixture should not be used
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IssueCategory {
     DateWeekdayMismatch,
     HistoricalFixture,
     SuspiciousTime,
     MissingData,
     DataInconsistency,
+    TimezoneResolution,
+    LowConfidence,
+    SourceDisagreement,
+    ScheduleClash,
+    StatusChange,
 }
 
-impl ValidatedFixture {
-    pub fn new(fixture: Fixture) -> Self {
-        let validation = FixtureValidator::validate(&fixture);
-        Self {
-            fixture,
-            validation,
+impl FixtureValidation {
+    /// Categorise issues by their most severe entry.
+    pub fn from_issues(issues: Vec<ValidationIssue>) -> Self {
+        if issues.iter().any(|i| i.severity == IssueSeverity::Critical) {
+            FixtureValidation::Invalid(issues)
+        } else if !issues.is_empty() {
+            FixtureValidation::ValidWithWarnings(issues)
+        } else {
+            FixtureValidation::Valid
         }
     }
 
-    pub fn is_usable(&self) -> bool {
-        !matches!(
-            self.validation,
-            FixtureValidation::Invalid(_) | FixtureValidation::Historical(_)
-        )
+    /// "Valid", "Valid with warnings", "Invalid" or "Historical".
+    pub fn label(&self) -> &'static str {
+        match self {
+            FixtureValidation::Valid => "Valid",
+            FixtureValidation::ValidWithWarnings(_) => "Valid with warnings",
+            FixtureValidation::Invalid(_) => "Invalid",
+            FixtureValidation::Historical(_) => "Historical",
+        }
     }
 
-    pub fn get_calendar_description(&self) -> String {
-        let mut description = format!(
-            "{} vs {} at {}\nCompetition: {}",
-            self.fixture.team, self.fixture.opponent, self.fixture.venue, self.fixture.competition
-        );
-
-        match &self.validation {
-            FixtureValidation::Valid => {}
-            FixtureValidation::ValidWithWarnings(issues) => {
-                description.push_str("\n\n⚠️ Data Quality Notes:");
-                for issue in issues {
-                    description.push_str(&format!("\n• {}", issue.message));
-                }
-            }
-            FixtureValidation::Invalid(issues) => {
-                description.push_str("\n\n❌ Data Issues Detected:");
-                for issue in issues {
-                    description.push_str(&format!("\n• {}", issue.message));
-                }
-            }
-            FixtureValidation::Historical(scraped_at) => {
-                description.push_str(&format!(
-                    "\n\n📅 Historical fixture (scraped {})",
-                    scraped_at.format("%Y-%m-%d")
-                ));
+    /// Issues behind the classification; none for valid and historical fixtures.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        match self {
+            FixtureValidation::ValidWithWarnings(issues) | FixtureValidation::Invalid(issues) => {
+                issues
             }
+            FixtureValidation::Valid | FixtureValidation::Historical(_) => &[],
         }
-
-        description
     }
 }
 
-pub struct FixtureValidator;
-
-impl FixtureValidator {
-    pub fn validate(fixture: &Fixture) -> FixtureValidation {
-        let mut issues = Vec::new();
-
-        // Check if fixture is historical
-        if fixture.datetime < Utc::now() {
-            return FixtureValidation::Historical(Utc::now());
-        }
-
-        // Check for reasonable date range (current year start to 2 years future)
-        if let Some(issue) = Self::validate_date_range(fixture) {
-            issues.push(issue);
-        }
-
-        // Validate date-weekday consistency (the sophisticated part!)
-        if let Some(issue) = Self::validate_date_consistency(fixture) {
-            issues.push(issue);
-        }
-
-        // Check for suspicious times
-        if let Some(issue) = Self::validate_fixture_time(fixture) {
-            issues.push(issue);
-        }
-
-        // Check for missing/suspicious data
-        issues.extend(Self::validate_fixture_data(fixture));
+impl ValidatedFixture {
+    pub fn new(fixture: Fixture) -> Self {
+        Self::validate_with(fixture, &ConfidenceThresholds::default(), &SystemClock)
+    }
 
-        // Categorize based on issue severity
-        let has_critical = issues.iter().any(|i| i.severity == IssueSeverity::Critical);
-        let has_errors = issues.iter().any(|i| i.severity == IssueSeverity::Error);
+    /// Validate with custom confidence thresholds.
+    pub fn with_thresholds(fixture: Fixture, thresholds: &ConfidenceThresholds) -> Self {
+        Self::validate_with(fixture, thresholds, &SystemClock)
+    }
 
-        if has_critical {
-            FixtureValidation::Invalid(issues)
-        } else if has_errors || !issues.is_empty() {
-            FixtureValidation::ValidWithWarnings(issues)
-        } else {
-            FixtureValidation::Valid
-        }
+    /// Validate as of the time `clock` reports (historical cut-off, date range).
+    pub fn with_clock(fixture: Fixture, clock: &dyn Clock) -> Self {
+        Self::validate_with(fixture, &ConfidenceThresholds::default(), clock)
     }
 
-    fn validate_date_consistency(fixture: &Fixture) -> Option<ValidationIssue> {
-        let london_time = fixture.to_london_time();
-        let date = london_time.date_naive();
-        let actual_weekday = date.weekday();
-
-        // Try to extract weekday from timezone_info or other sources
-        // This is where we'd parse "London Time (GMT/BST) - originally Sun Jan 15"
-        if let Some(expected_weekday) = Self::extract_expected_weekday(fixture) {
-            if actual_weekday != expected_weekday {
-                return Some(ValidationIssue {
-                    severity: IssueSeverity::Warning,
-                    category: IssueCategory::DateWeekdayMismatch,
-                    message: format!(
-                        "Date {} is a {}, but source indicated {}. Source may have incorrect weekday.",
-                        date.format("%b %d, %Y"),
-                        Self::weekday_to_string(actual_weekday),
-                        Self::weekday_to_string(expected_weekday)
-                    ),
-                    suggested_fix: Some("Verify fixture date. If date is correct, ignore weekday discrepancy.".to_string()),
-                });
-            }
+    pub fn validate_with(
+        fixture: Fixture,
+        thresholds: &ConfidenceThresholds,
+        clock: &dyn Clock,
+    ) -> Self {
+        let validation = FixtureValidator::validate_with(&fixture, thresholds, clock);
+        let confidence = ParseConfidence::for_fixture(&fixture);
+        Self {
+            fixture,
+            validation,
+            confidence,
         }
-
-        None
     }
 
-    fn validate_date_range(fixture: &Fixture) -> Option<ValidationIssue> {
-        let current_year = Utc::now().year();
-        let fixture_year = fixture.datetime.year();
-
-        // Accept fixtures from current year start to 2 years in future
-        let min_year = current_year;
-        let max_year = current_year + 2;
-
-        if fixture_year < min_year || fixture_year > max_year {
-            Some(ValidationIssue {
-                severity: IssueSeverity::Critical, // Critical = thrown out entirely
-                category: IssueCategory::DataInconsistency,
-                message: format!(
-                    "Fixture date {} is outside reasonable range ({}-{}). Fixtures only planned {} years ahead.",
-                    fixture.datetime.format("%Y-%m-%d"),
-                    min_year,
-                    max_year,
-                    max_year - min_year
-                ),
-                suggested_fix: Some("Check date parsing and source data accuracy".to_string()),
-            })
-        } else {
-            None
+    /// Validate against a custom rule set.
+    pub fn with_rules(fixture: Fixture, rules: &RuleSet, clock: &dyn Clock) -> Self {
+        let validation = rules.validate(&fixture, clock);
+        let confidence = ParseConfidence::for_fixture(&fixture);
+        Self {
+            fixture,
+            validation,
+            confidence,
         }
     }
 
-    fn validate_fixture_time(fixture: &Fixture) -> Option<ValidationIssue> {
-        let london_time = fixture.to_london_time();
-        let hour = london_time.hour();
-
-        // Flag unusual fixture times
-        if !(8..=23).contains(&hour) {
-            Some(ValidationIssue {
-                severity: IssueSeverity::Warning,
-                category: IssueCategory::SuspiciousTime,
-                message: format!(
-                    "Unusual fixture time: {}:{:02} London time",
-                    hour,
-                    london_time.minute()
-                ),
-                suggested_fix: Some("Verify time zone conversion is correct".to_string()),
-            })
-        } else {
-            None
-        }
+    pub fn is_usable(&self) -> bool {
+        !matches!(
+            self.validation,
+            FixtureValidation::Invalid(_) | FixtureValidation::Historical(_)
+        )
     }
 
-    fn validate_fixture_data(fixture: &Fixture) -> Vec<ValidationIssue> {
-        let mut issues = Vec::new();
-
-        // Check for placeholder/missing data
-        if fixture.opponent.contains("TBD") || fixture.opponent.contains("Unknown") {
-            issues.push(ValidationIssue {
-                severity: IssueSeverity::Warning,
-                category: IssueCategory::MissingData,
-                message: "Opponent not yet determined".to_string(),
-                suggested_fix: Some("Check source closer to fixture date".to_string()),
-            });
-        }
+    /// Event description from the `verbose` template, in London time.
+    ///
+    /// See [`crate::templates`] for other layouts.
+    pub fn get_calendar_description(&self) -> String {
+        EventTemplate::verbose()
+            .render(self, chrono_tz::Europe::London)
+            .description
+    }
+}
 
-        if fixture.venue.contains("Unknown") || fixture.venue.is_empty() {
-            issues.push(ValidationIssue {
-                severity: IssueSeverity::Error,
-                category: IssueCategory::MissingData,
-                message: "Venue information missing".to_string(),
-                suggested_fix: None,
-            });
-        }
+/// The standard rule set behind a one-call API.
+///
+/// For custom checks build a [`RuleSet`] instead.
+pub struct FixtureValidator;
 
-        issues
+impl FixtureValidator {
+    pub fn validate(fixture: &Fixture) -> FixtureValidation {
+        Self::validate_with(fixture, &ConfidenceThresholds::default(), &SystemClock)
     }
 
-    fn extract_expected_weekday(fixture: &Fixture) -> Option<Weekday> {
-        // Use rich ParseMetadata instead of primitive string parsing
-        if let Some(weekday_mismatch) = &fixture.parse_metadata.weekday_mismatch {
-            // Extract weekday from the claimed weekday in the mismatch data
-            let claimed = weekday_mismatch.claimed_weekday.to_lowercase();
-            if claimed.contains("sun") || claimed.starts_with("sun") {
-                Some(Weekday::Sun)
-            } else if claimed.contains("mon") || claimed.starts_with("mon") {
-                Some(Weekday::Mon)
-            } else if claimed.contains("tue") || claimed.starts_with("tue") {
-                Some(Weekday::Tue)
-            } else if claimed.contains("wed") || claimed.starts_with("wed") {
-                Some(Weekday::Wed)
-            } else if claimed.contains("thu") || claimed.starts_with("thu") {
-                Some(Weekday::Thu)
-            } else if claimed.contains("fri") || claimed.starts_with("fri") {
-                Some(Weekday::Fri)
-            } else if claimed.contains("sat") || claimed.starts_with("sat") {
-                Some(Weekday::Sat)
-            } else {
-                None
-            }
-        } else {
-            // No weekday mismatch recorded - parsing was exact
-            None
-        }
+    pub fn validate_with_thresholds(
+        fixture: &Fixture,
+        thresholds: &ConfidenceThresholds,
+    ) -> FixtureValidation {
+        Self::validate_with(fixture, thresholds, &SystemClock)
     }
 
-    fn weekday_to_string(weekday: Weekday) -> &'static str {
-        match weekday {
-            Weekday::Mon => "Monday",
-            Weekday::Tue => "Tuesday",
-            Weekday::Wed => "Wednesday",
-            Weekday::Thu => "Thursday",
-            Weekday::Fri => "Friday",
-            Weekday::Sat => "Saturday",
-            Weekday::Sun => "Sunday",
-        }
+    pub fn validate_with(
+        fixture: &Fixture,
+        thresholds: &ConfidenceThresholds,
+        clock: &dyn Clock,
+    ) -> FixtureValidation {
+        RuleSet::standard()
+            .with_rule(ConfidenceRule::new(*thresholds))
+            .validate(fixture, clock)
     }
 }
 
@@ -310,6 +214,11 @@ impl fmt::Display for IssueCategory {
             IssueCategory::SuspiciousTime => write!(f, "Suspicious Time"),
             IssueCategory::MissingData => write!(f, "Missing Data"),
             IssueCategory::DataInconsistency => write!(f, "Data Inconsistency"),
+            IssueCategory::TimezoneResolution => write!(f, "Timezone Resolution"),
+            IssueCategory::LowConfidence => write!(f, "Low Confidence"),
+            IssueCategory::SourceDisagreement => write!(f, "Source Disagreement"),
+            IssueCategory::ScheduleClash => write!(f, "Schedule Clash"),
+            IssueCategory::StatusChange => write!(f, "Status Change"),
         }
     }
 }
@@ -317,32 +226,20 @@ impl fmt::Display for IssueCategory {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::parsing::{ParseMetadata, ParsingStrategy};
-    use chrono::{TimeZone, Utc};
+    use crate::clock::FixedClock;
+    use crate::parsing::ParsingStrategy;
+    use crate::status::FixtureStatus;
+    use crate::test_support::{clock, fixture, utc};
 
+    /// Friday 15 Aug 2025, 17:30 BST
     fn create_test_fixture() -> Fixture {
-        // Create mock ParseMetadata for testing
-        let metadata = ParseMetadata {
-            original_source: "Fri Aug 15 16:30".to_string(),
-            weekday_mismatch: None, // No mismatch for valid test fixture
-            timezone_assumptions: "Parsed as Europe/London timezone".to_string(),
-            parsing_strategy: ParsingStrategy::ExactMatch,
-        };
-
-        Fixture::new(
-            "Arsenal".to_string(),
-            "Chelsea".to_string(),
-            Utc.with_ymd_and_hms(2025, 8, 15, 16, 30, 0).unwrap(), // Friday
-            "Emirates Stadium".to_string(),
-            "Premier League".to_string(),
-            metadata,
-        )
+        fixture().at(utc(2025, 8, 15, 16, 30)).build()
     }
 
     #[test]
     fn test_valid_fixture() {
         let fixture = create_test_fixture();
-        let validated = ValidatedFixture::new(fixture);
+        let validated = ValidatedFixture::with_clock(fixture, &clock());
 
         assert!(validated.is_usable());
         assert!(matches!(validated.validation, FixtureValidation::Valid));
@@ -351,9 +248,9 @@ mod tests {
     #[test]
     fn test_historical_fixture() {
         let mut fixture = create_test_fixture();
-        fixture.datetime = Utc.with_ymd_and_hms(2020, 1, 1, 15, 0, 0).unwrap();
+        fixture.datetime = utc(2020, 1, 1, 15, 0);
 
-        let validated = ValidatedFixture::new(fixture);
+        let validated = ValidatedFixture::with_clock(fixture, &clock());
         assert!(!validated.is_usable()); // Historical fixtures are not usable
         assert!(matches!(
             validated.validation,
@@ -372,7 +269,7 @@ mod tests {
         });
         fixture.parse_metadata.parsing_strategy = ParsingStrategy::WeekdayTolerant;
 
-        let validated = ValidatedFixture::new(fixture);
+        let validated = ValidatedFixture::with_clock(fixture, &clock());
         assert!(validated.is_usable()); // Still usable, just warned
 
         if let FixtureValidation::ValidWithWarnings(issues) = &validated.validation {
@@ -384,12 +281,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_historical_depends_on_clock() {
+        let kick_off = create_test_fixture().datetime;
+        let after_kick_off = FixedClock::new(kick_off + chrono::Duration::hours(3));
+
+        let validated = ValidatedFixture::with_clock(create_test_fixture(), &after_kick_off);
+        assert_eq!(
+            validated.validation,
+            FixtureValidation::Historical(after_kick_off.now())
+        );
+        assert!(!validated.is_usable());
+
+        // Three years early the fixture is outside the planning window
+        let too_early = clock().advanced_by(chrono::Duration::days(-3 * 365));
+        let validated = ValidatedFixture::with_clock(create_test_fixture(), &too_early);
+        assert!(matches!(
+            validated.validation,
+            FixtureValidation::Invalid(_)
+        ));
+    }
+
     #[test]
     fn test_suspicious_time() {
         let mut fixture = create_test_fixture();
-        fixture.datetime = Utc.with_ymd_and_hms(2025, 8, 15, 3, 0, 0).unwrap(); // 3 AM UTC = 4 AM BST
+        fixture.datetime = utc(2025, 8, 15, 3, 0); // 3 AM UTC = 4 AM BST
 
-        let validated = ValidatedFixture::new(fixture);
+        let validated = ValidatedFixture::with_clock(fixture, &clock());
 
         if let FixtureValidation::ValidWithWarnings(issues) = &validated.validation {
             assert!(issues
@@ -406,7 +324,7 @@ mod tests {
         fixture.opponent = "TBD Opponent".to_string();
         fixture.venue = "Unknown Venue".to_string();
 
-        let validated = ValidatedFixture::new(fixture);
+        let validated = ValidatedFixture::with_clock(fixture, &clock());
 
         if let FixtureValidation::ValidWithWarnings(issues) = &validated.validation {
             assert!(issues
@@ -422,9 +340,9 @@ mod tests {
     fn test_date_range_validation() {
         let mut fixture = create_test_fixture();
         // Set fixture date to year 2030 (beyond 2 year limit from 2025)
-        fixture.datetime = Utc.with_ymd_and_hms(2030, 8, 15, 16, 30, 0).unwrap();
+        fixture.datetime = utc(2030, 8, 15, 16, 30);
 
-        let validated = ValidatedFixture::new(fixture);
+        let validated = ValidatedFixture::with_clock(fixture, &clock());
         assert!(!validated.is_usable()); // Should be unusable due to critical date range issue
 
         if let FixtureValidation::Invalid(issues) = &validated.validation {
@@ -437,6 +355,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_called_off_fixture_stays_in_calendar() {
+        let fixture = create_test_fixture().with_status(FixtureStatus::Postponed);
+        let validated = ValidatedFixture::with_clock(fixture, &clock());
+
+        assert!(validated.is_usable());
+        assert!(validated
+            .get_calendar_description()
+            .contains("Status: POSTPONED"));
+    }
+
     #[test]
     fn test_calendar_description() {
         let mut fixture = create_test_fixture();
@@ -448,7 +377,7 @@ mod tests {
         });
         fixture.parse_metadata.parsing_strategy = ParsingStrategy::WeekdayTolerant;
 
-        let validated = ValidatedFixture::new(fixture);
+        let validated = ValidatedFixture::with_clock(fixture, &clock());
         let description = validated.get_calendar_description();
 
         assert!(description.contains("Arsenal vs Chelsea"));